@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM role_names
+	WHERE id BETWEEN 5 AND 8;
+DELETE FROM roles
+	WHERE id=4;
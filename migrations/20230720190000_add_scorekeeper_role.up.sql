@@ -0,0 +1,9 @@
+-- Add up migration script here
+INSERT INTO roles (id) VALUES (4);
+INSERT INTO role_names
+	(id, role, name, language)
+VALUES
+	(5, 4, 'scorekeeper', 1),
+	(6, 4, 'marqueur', 2),
+	(7, 2, 'réviseur', 2),
+	(8, 3, 'utilisateur', 2);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DELETE FROM users_roles
+	WHERE user_id=1
+	AND role=1;
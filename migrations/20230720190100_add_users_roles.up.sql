@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- the default admin user gets the admin role
+INSERT INTO users_roles
+	(user_id, role)
+VALUES
+	(1, 1);
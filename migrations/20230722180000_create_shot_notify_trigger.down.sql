@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS shot_inserted ON shots;
+DROP FUNCTION IF EXISTS notify_shot_inserted();
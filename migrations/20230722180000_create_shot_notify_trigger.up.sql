@@ -0,0 +1,20 @@
+-- Add up migration script here
+-- sends the game and shot id on the "shots" channel whenever a shot is recorded, so that live pages can be updated
+CREATE OR REPLACE FUNCTION notify_shot_inserted()
+RETURNS TRIGGER AS $$
+BEGIN
+  PERFORM pg_notify(
+    'shots',
+    json_build_object(
+      'game', (SELECT periods.game FROM periods WHERE periods.id=NEW.period),
+      'shot', NEW.id
+    )::TEXT
+  );
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER shot_inserted
+  AFTER INSERT ON shots
+  FOR EACH ROW
+  EXECUTE FUNCTION notify_shot_inserted();
@@ -0,0 +1,2 @@
+-- Add down migration script here
+-- The sequences are left where they are; moving them back would reuse ids.
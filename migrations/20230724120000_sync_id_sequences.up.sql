@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- Seed data is inserted with explicit ids, which leaves the id sequences behind;
+-- move every sequence past the largest id in its table, so new rows can be inserted.
+DO $$
+DECLARE
+	col RECORD;
+BEGIN
+	FOR col IN
+		SELECT seq.oid AS seq_id, tbl.relname AS table_name, attr.attname AS column_name
+		FROM pg_class seq
+		JOIN pg_depend dep ON dep.objid=seq.oid AND dep.deptype='a'
+		JOIN pg_class tbl ON tbl.oid=dep.refobjid
+		JOIN pg_attribute attr ON attr.attrelid=tbl.oid AND attr.attnum=dep.refobjsubid
+		WHERE seq.relkind='S'
+	LOOP
+		EXECUTE format(
+			'SELECT setval(%s, COALESCE((SELECT MAX(%I) FROM %I), 0) + 1, false)',
+			col.seq_id, col.column_name, col.table_name
+		);
+	END LOOP;
+END $$;
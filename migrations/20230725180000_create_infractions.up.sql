@@ -0,0 +1,6 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS infractions (
+  id SERIAL PRIMARY KEY NOT NULL,
+  -- a short code for the scoresheet, like "TRIP" or "HOOK"
+  short_name VARCHAR(16) NOT NULL
+);
@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS infraction_names (
+  id SERIAL PRIMARY KEY NOT NULL,
+  language INTEGER NOT NULL,
+  name VARCHAR(64) NOT NULL,
+  infraction INTEGER NOT NULL,
+  CONSTRAINT language_fk
+    FOREIGN KEY(language)
+      REFERENCES supported_languages(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT infraction_fk
+    FOREIGN KEY(infraction)
+      REFERENCES infractions(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT no_duplicated_infraction_names
+    UNIQUE (infraction, language)
+);
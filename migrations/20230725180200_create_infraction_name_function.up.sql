@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE FUNCTION infraction_name(infraction_id INT, lang_id INT)
+RETURNS TEXT
+AS $$
+SELECT
+  COALESCE(
+    MAX(a.name),
+    MAX(b.name),
+    MAX(c.name
+  )) AS name
+FROM infractions
+LEFT JOIN infraction_names a ON a.infraction = infractions.id AND a.language = lang_id
+LEFT JOIN infraction_names b ON b.infraction = infractions.id AND b.language = 1
+LEFT JOIN infraction_names c ON c.infraction = infractions.id
+WHERE infractions.id = infraction_id
+GROUP BY infractions.id;
+$$ LANGUAGE SQL;
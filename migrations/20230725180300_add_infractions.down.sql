@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM infraction_names
+  WHERE infraction BETWEEN 1 AND 15;
+DELETE FROM infractions
+  WHERE id BETWEEN 1 AND 15;
@@ -0,0 +1,53 @@
+-- Add up migration script here
+INSERT INTO infractions
+  (id, short_name)
+VALUES
+  (1, 'TRIP'),
+  (2, 'HOOK'),
+  (3, 'SLASH'),
+  (4, 'HOLD'),
+  (5, 'INTRF'),
+  (6, 'ROUGH'),
+  (7, 'HI-ST'),
+  (8, 'CROSS'),
+  (9, 'BOARD'),
+  (10, 'CHARG'),
+  (11, 'ELBOW'),
+  (12, 'TOO-MANY'),
+  (13, 'DELAY'),
+  (14, 'UNSPORT'),
+  (15, 'MISC');
+SELECT setval('infractions_id_seq', (SELECT MAX(id) FROM infractions));
+INSERT INTO infraction_names
+  (infraction, name, language)
+VALUES
+  (1, 'tripping', 1),
+  (2, 'hooking', 1),
+  (3, 'slashing', 1),
+  (4, 'holding', 1),
+  (5, 'interference', 1),
+  (6, 'roughing', 1),
+  (7, 'high-sticking', 1),
+  (8, 'cross-checking', 1),
+  (9, 'boarding', 1),
+  (10, 'charging', 1),
+  (11, 'elbowing', 1),
+  (12, 'too many players', 1),
+  (13, 'delay of game', 1),
+  (14, 'unsportsmanlike conduct', 1),
+  (15, 'misconduct', 1),
+  (1, 'faire trébucher', 2),
+  (2, 'accrocher', 2),
+  (3, 'cinglage', 2),
+  (4, 'retenir', 2),
+  (5, 'obstruction', 2),
+  (6, 'rudesse', 2),
+  (7, 'bâton élevé', 2),
+  (8, 'double-échec', 2),
+  (9, 'mise en échec contre la bande', 2),
+  (10, 'charge', 2),
+  (11, 'coup de coude', 2),
+  (12, 'trop de joueurs sur la glace', 2),
+  (13, 'retarder le match', 2),
+  (14, 'conduite antisportive', 2),
+  (15, 'inconduite', 2);
@@ -0,0 +1,36 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS penalties (
+  id SERIAL PRIMARY KEY NOT NULL,
+  -- which player committed the infraction
+  offender INTEGER NOT NULL,
+  -- which player sits in the box; usually the offender, but a teammate serves a goalie's or bench penalty
+  served_by INTEGER NOT NULL,
+  -- what the penalty was called for
+  infraction INTEGER NOT NULL,
+  -- penalty minutes: 2 for a minor, 5 for a major, 10 for a misconduct, etc.
+  minutes INTEGER NOT NULL,
+  -- which period was the penalty called in
+  period INTEGER NOT NULL,
+  -- when was the penalty called relative to the beginning of the period
+  period_time INTEGER NOT NULL,
+  -- when was the record created
+  created_at TIMESTAMPTZ NOT NULL DEFAULT current_timestamp,
+  CONSTRAINT offender_fk
+    FOREIGN KEY(offender)
+      REFERENCES game_players(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT served_by_fk
+    FOREIGN KEY(served_by)
+      REFERENCES game_players(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT infraction_fk
+    FOREIGN KEY(infraction)
+      REFERENCES infractions(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT period_fk
+    FOREIGN KEY(period)
+      REFERENCES periods(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT positive_minutes
+    CHECK (minutes > 0)
+);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE shots
+  DROP COLUMN IF EXISTS shooter_skaters,
+  DROP COLUMN IF EXISTS opponent_skaters,
+  DROP COLUMN IF EXISTS empty_net;
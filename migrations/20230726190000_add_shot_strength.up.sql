@@ -0,0 +1,12 @@
+-- Add up migration script here
+-- the manpower on ice when the shot was taken, as recorded by the scorekeeper;
+-- skaters do not include the goalies, nor an extra attacker for a pulled goalie
+ALTER TABLE shots
+  ADD COLUMN shooter_skaters INTEGER NOT NULL DEFAULT 5,
+  ADD COLUMN opponent_skaters INTEGER NOT NULL DEFAULT 5,
+  -- was the opponent's goalie pulled
+  ADD COLUMN empty_net BOOLEAN NOT NULL DEFAULT false,
+  ADD CONSTRAINT shooter_skaters_range
+    CHECK (shooter_skaters BETWEEN 3 AND 5),
+  ADD CONSTRAINT opponent_skaters_range
+    CHECK (opponent_skaters BETWEEN 3 AND 5);
@@ -0,0 +1,15 @@
+-- Add up migration script here
+-- classifies a shot by the manpower on ice:
+-- EN (empty net), PP (power play), SH (short-handed) or EV (even strength);
+-- an empty-net goal is EN no matter how many skaters were on the ice
+CREATE FUNCTION goal_strength(shooter_skaters INT, opponent_skaters INT, empty_net BOOLEAN)
+RETURNS TEXT
+AS $$
+SELECT
+  CASE
+    WHEN empty_net THEN 'EN'
+    WHEN shooter_skaters > opponent_skaters THEN 'PP'
+    WHEN shooter_skaters < opponent_skaters THEN 'SH'
+    ELSE 'EV'
+  END;
+$$ LANGUAGE SQL IMMUTABLE;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+DROP VIEW player_shot_stats_view;
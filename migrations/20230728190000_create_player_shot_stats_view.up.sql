@@ -0,0 +1,42 @@
+-- Add up migration script here
+-- one row per player per game they were dressed for
+CREATE OR REPLACE VIEW player_shot_stats_view
+AS SELECT
+  game_players.id AS game_player_id,
+  game_players.player AS player_id,
+  game_players.team AS team_id,
+  games.id AS game_id,
+  games.division AS division_id,
+  divisions.league AS league_id,
+  -- every shot taken, whether it was on net, missed or blocked
+  (SELECT COUNT(shots.id)
+    FROM shots
+    WHERE shots.shooter=game_players.id
+  ) AS shot_attempts,
+  (SELECT COUNT(shots.id)
+    FROM shots
+    WHERE shots.shooter=game_players.id
+      AND shots.on_net
+  ) AS shots_on_goal,
+  (SELECT COUNT(shots.id)
+    FROM shots
+    WHERE shots.shooter=game_players.id
+      AND NOT shots.on_net
+      AND shots.blocker IS NULL
+  ) AS missed_shots,
+  -- shots taken by this player which were blocked
+  (SELECT COUNT(shots.id)
+    FROM shots
+    WHERE shots.shooter=game_players.id
+      AND shots.blocker IS NOT NULL
+  ) AS shots_blocked,
+  -- shots of the other team which this player blocked
+  (SELECT COUNT(shots.id)
+    FROM shots
+    WHERE shots.blocker=game_players.id
+  ) AS blocks
+FROM game_players
+JOIN games
+  ON games.id=game_players.game
+JOIN divisions
+  ON divisions.id=games.division;
@@ -0,0 +1,22 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS points_systems (
+  id SERIAL PRIMARY KEY NOT NULL,
+  -- a short name for the standings, like "3-2-1-0"
+  name VARCHAR(32) NOT NULL,
+  reg_win INTEGER NOT NULL,
+  ot_win INTEGER NOT NULL,
+  ot_loss INTEGER NOT NULL,
+  reg_loss INTEGER NOT NULL,
+  tie INTEGER NOT NULL DEFAULT 0,
+  -- without ties, a game tied after regulation must still be decided; until then it gives no points
+  ties_allowed BOOLEAN NOT NULL DEFAULT false,
+  CONSTRAINT points_system_tie_ck
+    CHECK (ties_allowed OR tie = 0)
+);
+INSERT INTO points_systems
+  (id, name, reg_win, ot_win, ot_loss, reg_loss, tie, ties_allowed)
+VALUES
+  (1, '3-2-1-0', 3, 2, 1, 0, 0, false),
+  (2, '2-1-0', 2, 2, 0, 0, 1, true),
+  (3, '3-0', 3, 3, 0, 0, 0, false);
+SELECT setval('points_systems_id_seq', (SELECT MAX(id) FROM points_systems));
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE divisions
+  DROP COLUMN points_system;
+ALTER TABLE leagues
+  DROP COLUMN points_system;
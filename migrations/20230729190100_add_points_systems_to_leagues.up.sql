@@ -0,0 +1,15 @@
+-- Add up migration script here
+-- every league uses the IIHF 3-2-1-0 system unless configured otherwise
+ALTER TABLE leagues
+  ADD COLUMN points_system INTEGER NOT NULL DEFAULT 1,
+  ADD CONSTRAINT points_system_fk
+    FOREIGN KEY(points_system)
+      REFERENCES points_systems(id)
+      ON DELETE RESTRICT;
+-- a division without its own points system uses the league's
+ALTER TABLE divisions
+  ADD COLUMN points_system INTEGER,
+  ADD CONSTRAINT points_system_fk
+    FOREIGN KEY(points_system)
+      REFERENCES points_systems(id)
+      ON DELETE RESTRICT;
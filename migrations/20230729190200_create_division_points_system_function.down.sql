@@ -0,0 +1,2 @@
+-- Add down migration script here
+DROP FUNCTION division_points_system(INT);
@@ -0,0 +1,9 @@
+-- Add up migration script here
+-- the points system of the division, or of its league if the division does not set one
+CREATE FUNCTION division_points_system(division_id INT)
+RETURNS INTEGER AS $$
+  SELECT COALESCE(divisions.points_system, leagues.points_system)
+  FROM divisions
+  JOIN leagues ON leagues.id=divisions.league
+  WHERE divisions.id=division_id;
+$$ LANGUAGE SQL STABLE;
@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE OR REPLACE FUNCTION iihf_points(game_id INT, team_id INT)
+RETURNS INTEGER AS $$
+BEGIN
+  RETURN (
+    SELECT
+      (iihf_stats.reg_win * points_systems.reg_win) +
+      (iihf_stats.reg_loss * points_systems.reg_loss) +
+      (iihf_stats.ot_win * points_systems.ot_win) +
+      (iihf_stats.ot_loss * points_systems.ot_loss) +
+      (iihf_stats.tie * points_systems.tie) AS points
+    FROM iihf_stats(game_id, team_id) iihf_stats
+    JOIN games
+      ON games.id=iihf_stats.game
+    JOIN points_systems
+      ON points_systems.id=division_points_system(games.division));
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE period_types
+  DROP COLUMN IF EXISTS kind;
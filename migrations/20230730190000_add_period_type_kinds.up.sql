@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- whether a period is part of regulation time, an overtime or the shootout;
+-- the result of a game depends on the kind of period its deciding goal was scored in
+ALTER TABLE period_types
+  ADD COLUMN kind VARCHAR(3) NOT NULL DEFAULT 'OT',
+  ADD CONSTRAINT period_type_kind
+    CHECK (kind IN ('REG', 'OT', 'SO'));
+UPDATE period_types SET kind='REG' WHERE id IN (1, 2, 3);
+UPDATE period_types SET kind='SO' WHERE id=5;
+ALTER TABLE period_types
+  ALTER COLUMN kind DROP DEFAULT;
@@ -0,0 +1,29 @@
+-- Add up migration script here
+-- shootout attempts are kept apart from shots, so they never count in the shot, goal or goalie stats
+CREATE TABLE IF NOT EXISTS shootout_attempts (
+  id SERIAL PRIMARY KEY NOT NULL,
+  game INTEGER NOT NULL,
+  shooter INTEGER NOT NULL,
+  goalie INTEGER NOT NULL,
+  -- 1 for the first attempt of the shootout, 2 for the second, and so on, counting both teams
+  attempt_number INTEGER NOT NULL,
+  goal BOOLEAN NOT NULL,
+  -- when was the record created
+  created_at TIMESTAMPTZ NOT NULL DEFAULT current_timestamp,
+  CONSTRAINT game_fk
+    FOREIGN KEY(game)
+      REFERENCES games(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT shooter_fk
+    FOREIGN KEY(shooter)
+      REFERENCES game_players(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT goalie_fk
+    FOREIGN KEY(goalie)
+      REFERENCES game_players(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT positive_attempt_number
+    CHECK (attempt_number > 0),
+  CONSTRAINT unique_attempt_number
+    UNIQUE (game, attempt_number)
+);
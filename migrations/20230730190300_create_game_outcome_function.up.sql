@@ -0,0 +1,70 @@
+-- Add up migration script here
+-- The result of a game for one of its teams:
+-- REG_WIN, REG_LOSS, OT_WIN, OT_LOSS, SO_WIN, SO_LOSS or TIE.
+--
+-- The winner's deciding goal is the one after the loser's last goal.
+-- Regulation comes before every overtime, so it was scored in regulation exactly when
+-- the winner scored more goals in regulation than the loser did in the whole game.
+-- A game still tied after overtime is decided by the goals of the shootout attempts.
+CREATE FUNCTION game_outcome(game_id INT, team_id INT)
+RETURNS VARCHAR AS $$
+DECLARE
+	opponent_team_id INTEGER;
+	team_goals INTEGER;
+	opponent_goals INTEGER;
+	winner_regulation_goals INTEGER;
+	team_shootout_goals INTEGER;
+	opponent_shootout_goals INTEGER;
+BEGIN
+	SELECT
+    teams.id
+  INTO
+    opponent_team_id
+  FROM games
+  JOIN teams
+    ON (teams.id=games.team_home
+    OR teams.id=games.team_away)
+  WHERE games.id=game_id
+    AND teams.id!=team_id;
+	team_goals := goals(game_id, team_id);
+	opponent_goals := goals(game_id, opponent_team_id);
+	IF team_goals <> opponent_goals THEN
+		SELECT
+			COUNT(shots.id)
+		INTO
+			winner_regulation_goals
+		FROM shots
+		JOIN game_players
+			ON game_players.id=shots.shooter
+		JOIN periods
+			ON periods.id=shots.period
+		JOIN period_types
+			ON period_types.id=periods.period_type
+		WHERE shots.goal=true
+			AND periods.game=game_id
+			AND period_types.kind='REG'
+			AND game_players.team=(CASE WHEN team_goals > opponent_goals THEN team_id ELSE opponent_team_id END);
+		IF team_goals > opponent_goals THEN
+			RETURN (CASE WHEN winner_regulation_goals > opponent_goals THEN 'REG_WIN' ELSE 'OT_WIN' END);
+		END IF;
+		RETURN (CASE WHEN winner_regulation_goals > team_goals THEN 'REG_LOSS' ELSE 'OT_LOSS' END);
+	END IF;
+	SELECT
+		COUNT(CASE WHEN game_players.team=team_id THEN shootout_attempts.id END),
+		COUNT(CASE WHEN game_players.team=opponent_team_id THEN shootout_attempts.id END)
+	INTO
+		team_shootout_goals,
+		opponent_shootout_goals
+	FROM shootout_attempts
+	JOIN game_players
+		ON game_players.id=shootout_attempts.shooter
+	WHERE shootout_attempts.game=game_id
+		AND shootout_attempts.goal=true;
+	IF team_shootout_goals > opponent_shootout_goals THEN
+		RETURN 'SO_WIN';
+	ELSIF team_shootout_goals < opponent_shootout_goals THEN
+		RETURN 'SO_LOSS';
+	END IF;
+	RETURN 'TIE';
+END;
+$$ LANGUAGE plpgsql;
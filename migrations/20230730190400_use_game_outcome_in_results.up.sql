@@ -0,0 +1,37 @@
+-- Add up migration script here
+-- a shootout win or loss counts as an overtime win or loss
+CREATE OR REPLACE FUNCTION reg_win(game_id INT, team_id INT)
+RETURNS INTEGER
+AS $$
+BEGIN
+	RETURN (SELECT (CASE WHEN game_outcome(game_id, team_id) IN ('REG_WIN') THEN 1 ELSE 0 END));
+END;
+$$ LANGUAGE plpgsql;
+CREATE OR REPLACE FUNCTION reg_loss(game_id INT, team_id INT)
+RETURNS INTEGER
+AS $$
+BEGIN
+	RETURN (SELECT (CASE WHEN game_outcome(game_id, team_id) IN ('REG_LOSS') THEN 1 ELSE 0 END));
+END;
+$$ LANGUAGE plpgsql;
+CREATE OR REPLACE FUNCTION ot_win(game_id INT, team_id INT)
+RETURNS INTEGER
+AS $$
+BEGIN
+	RETURN (SELECT (CASE WHEN game_outcome(game_id, team_id) IN ('OT_WIN', 'SO_WIN') THEN 1 ELSE 0 END));
+END;
+$$ LANGUAGE plpgsql;
+CREATE OR REPLACE FUNCTION ot_loss(game_id INT, team_id INT)
+RETURNS INTEGER
+AS $$
+BEGIN
+	RETURN (SELECT (CASE WHEN game_outcome(game_id, team_id) IN ('OT_LOSS', 'SO_LOSS') THEN 1 ELSE 0 END));
+END;
+$$ LANGUAGE plpgsql;
+CREATE OR REPLACE FUNCTION tie(game_id INT, team_id INT)
+RETURNS INTEGER
+AS $$
+BEGIN
+	RETURN (SELECT (CASE WHEN game_outcome(game_id, team_id) IN ('TIE') THEN 1 ELSE 0 END));
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- every game with at least one period counts, even if nobody scored in it
+CREATE OR REPLACE VIEW team_points_view
+AS SELECT
+  teams.id AS team_id,
+  games.id AS game_id,
+  games.division AS division_id,
+  divisions.league AS league_id,
+  reg_win(games.id, teams.id) AS reg_wins,
+  reg_loss(games.id, teams.id) AS reg_losses,
+  ot_win(games.id, teams.id) AS ot_wins,
+  ot_loss(games.id, teams.id) AS ot_losses,
+  tie(games.id, teams.id) AS ties,
+  iihf_points(games.id, teams.id) AS points
+FROM games
+JOIN divisions
+  ON divisions.id=games.division
+JOIN teams
+  ON teams.id=games.team_home
+  OR teams.id=games.team_away
+WHERE EXISTS (SELECT 1 FROM periods WHERE periods.game=games.id);
@@ -0,0 +1,14 @@
+-- Add up migration script here
+-- a season of a league; its games are the ones which start between these dates
+CREATE TABLE IF NOT EXISTS seasons (
+  id SERIAL PRIMARY KEY NOT NULL,
+  league INTEGER NOT NULL,
+  start_date DATE NOT NULL,
+  end_date DATE NOT NULL,
+  CONSTRAINT league_fk
+    FOREIGN KEY(league)
+      REFERENCES leagues(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT season_ends_after_start
+    CHECK (end_date >= start_date)
+);
@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS season_names (
+  id SERIAL PRIMARY KEY NOT NULL,
+  language INTEGER NOT NULL,
+  name VARCHAR(64) NOT NULL,
+  season INTEGER NOT NULL,
+  CONSTRAINT language_fk
+    FOREIGN KEY(language)
+      REFERENCES supported_languages(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT season_fk
+    FOREIGN KEY(season)
+      REFERENCES seasons(id)
+      ON DELETE RESTRICT,
+  CONSTRAINT no_duplicated_season_names
+    UNIQUE (season, language)
+);
@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE FUNCTION season_name(season_id INT, lang_id INT)
+RETURNS TEXT
+AS $$
+SELECT
+  COALESCE(
+    MAX(a.name),
+    MAX(b.name),
+    MAX(c.name
+  )) AS name
+FROM seasons
+LEFT JOIN season_names a ON a.season = seasons.id AND a.language = lang_id
+LEFT JOIN season_names b ON b.season = seasons.id AND b.language = 1
+LEFT JOIN season_names c ON c.season = seasons.id
+WHERE seasons.id = season_id
+GROUP BY seasons.id;
+$$ LANGUAGE SQL;
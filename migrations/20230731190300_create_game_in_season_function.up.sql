@@ -0,0 +1,17 @@
+-- Add up migration script here
+-- whether a game was played in a season of its league; every game is in the NULL season
+CREATE FUNCTION game_in_season(game_id INT, season_id INT)
+RETURNS BOOLEAN
+AS $$
+SELECT
+  season_id IS NULL
+  OR EXISTS (
+    SELECT seasons.id
+    FROM games
+    JOIN divisions ON divisions.id=games.division
+    JOIN seasons ON seasons.league=divisions.league
+    WHERE games.id=game_id
+      AND seasons.id=season_id
+      AND games.start_at::DATE BETWEEN seasons.start_date AND seasons.end_date
+  );
+$$ LANGUAGE SQL;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM season_names
+  WHERE season=1;
+DELETE FROM seasons
+  WHERE id=1;
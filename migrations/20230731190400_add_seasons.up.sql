@@ -0,0 +1,11 @@
+-- Add up migration script here
+INSERT INTO seasons
+  (id, league, start_date, end_date)
+VALUES
+  (1, 1, '2022-01-01', '2022-12-31');
+SELECT setval('seasons_id_seq', (SELECT MAX(id) FROM seasons));
+INSERT INTO season_names
+  (season, name, language)
+VALUES
+  (1, '2022', 1),
+  (1, '2022', 2);
@@ -0,0 +1,17 @@
+-- Add up migration script here
+-- a player's sight classification from the effective date until their next classification
+-- B1, B2 and B3 are the IBSA sight classes; SIGHTED is only for goalies
+CREATE TABLE IF NOT EXISTS player_classifications (
+  id SERIAL PRIMARY KEY NOT NULL,
+  player INTEGER NOT NULL,
+  classification TEXT NOT NULL,
+  effective_date DATE NOT NULL,
+  CONSTRAINT player_fk
+    FOREIGN KEY(player)
+      REFERENCES players(id)
+      ON DELETE CASCADE,
+  CONSTRAINT known_classification
+    CHECK (classification IN ('B1', 'B2', 'B3', 'SIGHTED')),
+  CONSTRAINT one_classification_per_day
+    UNIQUE (player, effective_date)
+);
@@ -0,0 +1,12 @@
+-- Add up migration script here
+-- the classification of a player on a date, or NULL if they were not classified yet
+CREATE FUNCTION player_classification(player_id INT, on_date DATE)
+RETURNS TEXT
+AS $$
+SELECT classification
+FROM player_classifications
+WHERE player=player_id
+  AND effective_date <= on_date
+ORDER BY effective_date DESC
+LIMIT 1;
+$$ LANGUAGE SQL;
@@ -0,0 +1,22 @@
+-- Add up migration script here
+-- how many players of a classification each team may dress for a game of a division
+CREATE TABLE IF NOT EXISTS classification_rules (
+  id SERIAL PRIMARY KEY NOT NULL,
+  division INTEGER NOT NULL,
+  classification TEXT NOT NULL,
+  -- NULL for no limit
+  min_players INTEGER,
+  max_players INTEGER,
+  CONSTRAINT division_fk
+    FOREIGN KEY(division)
+      REFERENCES divisions(id)
+      ON DELETE CASCADE,
+  CONSTRAINT known_classification
+    CHECK (classification IN ('B1', 'B2', 'B3', 'SIGHTED')),
+  CONSTRAINT has_a_limit
+    CHECK (min_players IS NOT NULL OR max_players IS NOT NULL),
+  CONSTRAINT min_below_max
+    CHECK (min_players <= max_players),
+  CONSTRAINT one_rule_per_classification
+    UNIQUE (division, classification)
+);
@@ -0,0 +1,14 @@
+-- Add up migration script here
+-- goalies are sighted, the other players of the 2022 tournament were classified B3
+INSERT INTO player_classifications
+  (player, classification, effective_date)
+SELECT DISTINCT ON (game_players.player)
+  game_players.player,
+  CASE WHEN positions.short_name = 'G' THEN 'SIGHTED' ELSE 'B3' END,
+  '2022-01-01'::DATE
+FROM game_players
+JOIN positions ON positions.id=game_players.position
+WHERE positions.short_name NOT IN ('HC', 'AC')
+ORDER BY
+  game_players.player,
+  positions.short_name = 'G' DESC;
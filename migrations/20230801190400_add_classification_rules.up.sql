@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- each team may dress at most two sighted players, who can only be goalies
+INSERT INTO classification_rules
+  (division, classification, min_players, max_players)
+SELECT divisions.id, 'SIGHTED', NULL, 2
+FROM divisions;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE game_players
+  DROP CONSTRAINT IF EXISTS one_lineup_entry_per_player;
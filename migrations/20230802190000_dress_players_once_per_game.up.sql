@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- a player is dressed for one team at one position in a game
+ALTER TABLE game_players
+  ADD CONSTRAINT one_lineup_entry_per_player
+    UNIQUE (game, player);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE games
+  DROP CONSTRAINT IF EXISTS home_is_not_away;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- a team can not play against itself
+ALTER TABLE games
+  ADD CONSTRAINT home_is_not_away
+    CHECK (team_home <> team_away);
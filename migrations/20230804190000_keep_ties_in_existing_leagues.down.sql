@@ -0,0 +1,9 @@
+-- Add down migration script here
+UPDATE leagues
+  SET points_system=1
+  WHERE points_system=4;
+UPDATE divisions
+  SET points_system=1
+  WHERE points_system=4;
+DELETE FROM points_systems
+  WHERE id=4;
@@ -0,0 +1,10 @@
+-- Add up migration script here
+-- the leagues from before points systems counted a tie as 2 points; keep their standings as they were
+INSERT INTO points_systems
+  (id, name, reg_win, ot_win, ot_loss, reg_loss, tie, ties_allowed)
+VALUES
+  (4, '3-2-1-0 (ties)', 3, 2, 1, 0, 2, true);
+SELECT setval('points_systems_id_seq', (SELECT MAX(id) FROM points_systems));
+UPDATE leagues
+  SET points_system=4
+  WHERE points_system=1;
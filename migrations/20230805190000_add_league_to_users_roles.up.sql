@@ -0,0 +1,8 @@
+-- Add up migration script here
+-- the league a role is limited to, like the league of a scorekeeper; NULL for every league
+ALTER TABLE users_roles
+  ADD COLUMN league INTEGER,
+  ADD CONSTRAINT league_fk
+    FOREIGN KEY(league)
+      REFERENCES leagues(id)
+      ON DELETE RESTRICT;
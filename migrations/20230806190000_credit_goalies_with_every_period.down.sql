@@ -0,0 +1,47 @@
+-- Add down migration script here
+-- one row per goalie per game they faced a shot in;
+-- empty-net shots count against the team, not the goalie who was pulled
+CREATE OR REPLACE VIEW goalie_stats_view
+AS SELECT
+  goalies.id AS game_player_id,
+  goalies.player AS player_id,
+  goalies.team AS team_id,
+  games.id AS game_id,
+  games.division AS division_id,
+  divisions.league AS league_id,
+  COUNT(shots.id) FILTER (WHERE shots.on_net) AS shots_against,
+  COUNT(shots.id) FILTER (WHERE shots.on_net AND NOT shots.goal) AS saves,
+  COUNT(shots.id) FILTER (WHERE shots.goal) AS goals_against,
+  -- a goalie is credited with every period they faced a shot in
+  (SELECT SUM(periods.period_length)
+    FROM periods
+    WHERE periods.id IN (
+      SELECT goalie_shots.period
+      FROM shots goalie_shots
+      WHERE goalie_shots.goalie=goalies.id
+        AND NOT goalie_shots.empty_net
+    )
+  ) AS seconds_played,
+  -- a shutout is only credited when no other goalie of the team faced a shot
+  CASE WHEN COUNT(shots.id) FILTER (WHERE shots.goal)=0
+    AND NOT EXISTS (
+      SELECT 1
+      FROM shots other_shots
+      JOIN game_players other_goalies ON other_goalies.id=other_shots.goalie
+      WHERE other_goalies.game=goalies.game
+        AND other_goalies.team=goalies.team
+        AND other_goalies.id<>goalies.id
+        AND NOT other_shots.empty_net
+    )
+    THEN 1 ELSE 0
+  END AS shutouts
+FROM shots
+JOIN game_players goalies
+  ON goalies.id=shots.goalie
+JOIN games
+  ON games.id=goalies.game
+JOIN divisions
+  ON divisions.id=games.division
+WHERE NOT shots.empty_net
+GROUP BY goalies.id,games.id,divisions.id;
+DROP VIEW goalie_periods_view;
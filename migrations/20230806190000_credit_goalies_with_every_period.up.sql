@@ -0,0 +1,93 @@
+-- Add up migration script here
+-- the seconds each goalie is credited with, one row per goalie per period of the game:
+-- a period goes to the goalies of the team who faced a shot in it, split evenly between them;
+-- a period in which the team faced no shot goes to whoever was in net in the closest period before it,
+-- or after it if there was no earlier one, so a goalie who played the whole game gets every period
+CREATE VIEW goalie_periods_view
+AS WITH team_periods AS (
+  SELECT
+    periods.id AS period_id,
+    periods.game AS game_id,
+    periods.period_type,
+    periods.period_length,
+    teams.id AS team_id
+  FROM periods
+  JOIN games
+    ON games.id=periods.game
+  JOIN teams
+    ON teams.id=games.team_home
+    OR teams.id=games.team_away
+), faced AS (
+  SELECT DISTINCT
+    shots.period AS period_id,
+    goalies.team AS team_id,
+    goalies.id AS game_player_id
+  FROM shots
+  JOIN game_players goalies
+    ON goalies.id=shots.goalie
+  WHERE NOT shots.empty_net
+), in_net AS (
+  SELECT
+    team_periods.*,
+    (SELECT closest.period_id
+      FROM team_periods closest
+      WHERE closest.game_id=team_periods.game_id
+        AND closest.team_id=team_periods.team_id
+        AND EXISTS (
+          SELECT 1
+          FROM faced
+          WHERE faced.period_id=closest.period_id
+            AND faced.team_id=closest.team_id
+        )
+      ORDER BY
+        closest.period_type > team_periods.period_type,
+        ABS(closest.period_type - team_periods.period_type)
+      LIMIT 1
+    ) AS credited_period_id
+  FROM team_periods
+)
+SELECT
+  faced.game_player_id,
+  in_net.period_id,
+  in_net.period_length / COUNT(*) OVER (PARTITION BY in_net.period_id, in_net.team_id) AS seconds_played
+FROM in_net
+JOIN faced
+  ON faced.period_id=in_net.credited_period_id
+  AND faced.team_id=in_net.team_id;
+CREATE OR REPLACE VIEW goalie_stats_view
+AS SELECT
+  goalies.id AS game_player_id,
+  goalies.player AS player_id,
+  goalies.team AS team_id,
+  games.id AS game_id,
+  games.division AS division_id,
+  divisions.league AS league_id,
+  COUNT(shots.id) FILTER (WHERE shots.on_net) AS shots_against,
+  COUNT(shots.id) FILTER (WHERE shots.on_net AND NOT shots.goal) AS saves,
+  COUNT(shots.id) FILTER (WHERE shots.goal) AS goals_against,
+  (SELECT SUM(goalie_periods_view.seconds_played)
+    FROM goalie_periods_view
+    WHERE goalie_periods_view.game_player_id=goalies.id
+  )::BIGINT AS seconds_played,
+  -- a shutout is only credited when no other goalie of the team faced a shot
+  CASE WHEN COUNT(shots.id) FILTER (WHERE shots.goal)=0
+    AND NOT EXISTS (
+      SELECT 1
+      FROM shots other_shots
+      JOIN game_players other_goalies ON other_goalies.id=other_shots.goalie
+      WHERE other_goalies.game=goalies.game
+        AND other_goalies.team=goalies.team
+        AND other_goalies.id<>goalies.id
+        AND NOT other_shots.empty_net
+    )
+    THEN 1 ELSE 0
+  END AS shutouts
+FROM shots
+JOIN game_players goalies
+  ON goalies.id=shots.goalie
+JOIN games
+  ON games.id=goalies.game
+JOIN divisions
+  ON divisions.id=games.division
+WHERE NOT shots.empty_net
+GROUP BY goalies.id,games.id,divisions.id;
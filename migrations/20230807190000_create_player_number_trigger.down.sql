@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TRIGGER player_number_is_free ON game_players;
+DROP FUNCTION check_player_number_is_free;
@@ -0,0 +1,38 @@
+-- Add up migration script here
+-- a number is worn by one player of a team in a game; coaches (HC and AC, the STAFF_POSITIONS) do not need one.
+-- Some games were recorded with two players sharing a number, so this can not be a unique index;
+-- instead, every new or changed lineup entry is checked.
+CREATE FUNCTION check_player_number_is_free()
+RETURNS TRIGGER AS $$
+BEGIN
+	IF EXISTS (
+		SELECT positions.id
+		FROM positions
+		WHERE positions.id=NEW.position
+			AND positions.short_name IN ('HC', 'AC')
+	) THEN
+		RETURN NEW;
+	END IF;
+	-- lineup changes for the same team in the same game wait for each other, so two of them can not take the same number
+	PERFORM pg_advisory_xact_lock(NEW.game, NEW.team);
+	IF EXISTS (
+		SELECT dressed.id
+		FROM game_players dressed
+		JOIN positions
+			ON positions.id=dressed.position
+		WHERE dressed.game=NEW.game
+			AND dressed.team=NEW.team
+			AND dressed.player_number=NEW.player_number
+			AND dressed.id<>NEW.id
+			AND positions.short_name NOT IN ('HC', 'AC')
+	) THEN
+		RAISE EXCEPTION 'Number % is already taken on team % in game %.', NEW.player_number, NEW.team, NEW.game
+			USING ERRCODE = 'unique_violation', CONSTRAINT = 'player_number_is_free';
+	END IF;
+	RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+CREATE TRIGGER player_number_is_free
+  BEFORE INSERT OR UPDATE OF game, team, player_number, position ON game_players
+  FOR EACH ROW
+  EXECUTE FUNCTION check_player_number_is_free();
@@ -0,0 +1,910 @@
+use crate::auth::{Admin, AuthRejection, Authorized};
+use crate::forms::validate_game;
+use crate::languages::SupportedLanguage;
+use crate::model::{
+    ClassificationRule, Division, Game, GamePlayer, Infraction, InsertGame, League, Penalty, Period,
+    Player, PlayerClassification, PointsSystem, Season, ShootoutAttempt, Shot, Team,
+};
+use crate::standings::{Standing, Tiebreak};
+use crate::views::{
+    division_goalie_stats, division_iihf_stats, division_player_stats, division_standings,
+    division_team_stats, game_box_score, game_goalie_stats, game_goals, game_penalties,
+    game_play_by_play, game_score, game_shootout, league_goalie_stats, GoalDetails, GoalieStats,
+    IihfStatsI64, PenaltyDetails, PlayerStats, ShootoutAttemptDetails, ShotDetails, TeamStats,
+};
+use crate::ServerState;
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use axum_extra::extract::WithRejection;
+use ormx::Table;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+/// The OpenAPI document for the stats and game scheduling endpoints.
+/// It is checked in at `openapi.json`; see the `check_openapi_is_up_to_date` test.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "IBIHF Statistics",
+        version = "1",
+        description = "Statistics of the International Blind Ice Hockey Federation.",
+        license(name = "AGPL-3.0")
+    ),
+    paths(
+        goalie_stats_for_league,
+        iihf_stats_for_division,
+        standings_for_division,
+        goalie_stats_for_division,
+        team_stats_for_division,
+        player_stats_for_division,
+        box_score_for_game,
+        score_for_game,
+        goals_for_game,
+        play_by_play_for_game,
+        penalties_for_game,
+        shootout_for_game,
+        goalie_stats_for_game,
+        lifetime_stats_for_player,
+        lifetime_goalie_stats_for_player,
+        latest_goals_for_player,
+        create_game,
+        update_game,
+    ),
+    components(schemas(
+        IihfStatsI64,
+        Standing,
+        Tiebreak,
+        PlayerStats,
+        GoalDetails,
+        ShotDetails,
+        PenaltyDetails,
+        ShootoutAttemptDetails,
+        GoalieStats,
+        TeamStats,
+        SupportedLanguage,
+        Game,
+        InsertGame,
+        ErrorBody
+    ))
+)]
+pub struct ApiDoc;
+
+/// An error from the JSON API; it is always returned as `{"error": "..."}`.
+#[derive(Debug)]
+pub enum ApiError {
+    NotFound,
+    BadRequest(String),
+    /// There is no valid session.
+    Unauthorized,
+    /// The user does not have the role the endpoint needs.
+    Forbidden,
+    /// The request was understood, but breaks a rule; the reasons are in the requested language.
+    Invalid(String),
+    Database(sqlx::Error),
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody {
+    error: String,
+}
+
+impl From<sqlx::Error> for ApiError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => ApiError::NotFound,
+            e => ApiError::Database(e),
+        }
+    }
+}
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+impl From<AuthRejection> for ApiError {
+    fn from(rejection: AuthRejection) -> Self {
+        match rejection {
+            AuthRejection::NotLoggedIn(_) => ApiError::Unauthorized,
+            AuthRejection::MissingRole(_) => ApiError::Forbidden,
+            AuthRejection::Database(_, e) => ApiError::Database(e),
+        }
+    }
+}
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Not found.".to_string()),
+            ApiError::BadRequest(reason) => (StatusCode::BAD_REQUEST, reason),
+            ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "Not logged in.".to_string()),
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Forbidden.".to_string()),
+            ApiError::Invalid(reason) => (StatusCode::UNPROCESSABLE_ENTITY, reason),
+            ApiError::Database(e) => {
+                log::error!("Database error in API request: {e}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error.".to_string(),
+                )
+            }
+        };
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+type IdPath = WithRejection<Path<i32>, ApiError>;
+type LangQuery = WithRejection<Query<LangParam>, ApiError>;
+type SeasonQuery = WithRejection<Query<SeasonParam>, ApiError>;
+type GameBody = WithRejection<Json<InsertGame>, ApiError>;
+type AdminAuth = WithRejection<Authorized<Admin>, ApiError>;
+
+/// The `?lang=` parameter, used for localized names; it defaults to English.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LangParam {
+    /// The language of team, period and league names.
+    lang: Option<SupportedLanguage>,
+}
+impl LangParam {
+    fn language(&self) -> SupportedLanguage {
+        self.lang.unwrap_or(SupportedLanguage::English)
+    }
+    fn lang_id(&self) -> i32 {
+        self.language().into()
+    }
+}
+
+/// The `?season=` parameter of the league and division stats; without it, every season is counted.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SeasonParam {
+    /// Only count the games of this season.
+    season: Option<i32>,
+}
+
+/// Generates `all` and `by id` handlers for a table with localized names.
+macro_rules! get_localized {
+    ($ty:ident, $func_all:ident, $func_by_id:ident) => {
+        async fn $func_all(
+            State(server_config): State<ServerState>,
+            WithRejection(Query(lang), _): LangQuery,
+        ) -> ApiResult<Vec<$ty>> {
+            Ok(Json($ty::all(&server_config.db_pool, lang.lang_id()).await?))
+        }
+        async fn $func_by_id(
+            State(server_config): State<ServerState>,
+            WithRejection(Path(id), _): IdPath,
+            WithRejection(Query(lang), _): LangQuery,
+        ) -> ApiResult<$ty> {
+            $ty::get(&server_config.db_pool, id, lang.lang_id())
+                .await?
+                .map(Json)
+                .ok_or(ApiError::NotFound)
+        }
+    };
+}
+/// Generates `all` and `by id` handlers for an `ormx` table.
+macro_rules! get_table {
+    ($ty:ident, $func_all:ident, $func_by_id:ident) => {
+        async fn $func_all(State(server_config): State<ServerState>) -> ApiResult<Vec<$ty>> {
+            Ok(Json($ty::all(&*server_config.db_pool).await?))
+        }
+        async fn $func_by_id(
+            State(server_config): State<ServerState>,
+            WithRejection(Path(id), _): IdPath,
+        ) -> ApiResult<$ty> {
+            Ok(Json($ty::get(&*server_config.db_pool, id).await?))
+        }
+    };
+}
+
+get_localized!(League, leagues, league);
+get_localized!(Division, divisions, division);
+get_localized!(Team, teams, team);
+get_localized!(Game, games, game);
+get_localized!(Infraction, infractions, infraction);
+get_localized!(Season, seasons, season);
+get_table!(Player, players, player);
+get_table!(GamePlayer, game_players, game_player);
+get_table!(Shot, shots, shot);
+get_table!(Period, periods, period);
+get_table!(Penalty, penalties, penalty);
+get_table!(PointsSystem, points_systems, points_system);
+get_table!(ShootoutAttempt, shootout_attempts, shootout_attempt);
+get_table!(PlayerClassification, player_classifications, player_classification);
+get_table!(ClassificationRule, classification_rules, classification_rule);
+
+async fn divisions_for_league(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<Division>> {
+    let league = League::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        Division::by_league(&server_config.db_pool, league.id, lang.lang_id()).await?,
+    ))
+}
+
+async fn seasons_for_league(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<Season>> {
+    let league = League::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        Season::for_league(&server_config.db_pool, league.id, lang.lang_id()).await?,
+    ))
+}
+
+async fn points_system_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<PointsSystem> {
+    Ok(Json(PointsSystem::for_division(&server_config.db_pool, id).await?))
+}
+
+async fn games_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<Game>> {
+    let division = Division::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        Game::by_division(&server_config.db_pool, division.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/leagues/{id}/goalie-stats",
+    params(("id" = i32, Path, description = "League id"), SeasonParam),
+    responses(
+        (status = 200, description = "Stats of every goalie who faced a shot in the league", body = [GoalieStats]),
+        (status = 404, description = "The league does not exist", body = ErrorBody)
+    )
+)]
+async fn goalie_stats_for_league(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<GoalieStats>> {
+    let league = League::get(&server_config.db_pool, id, SupportedLanguage::English.into())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        league_goalie_stats(&server_config.db_pool, league.id, season.season).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/divisions/{id}/iihf-stats",
+    params(("id" = i32, Path, description = "Division id"), LangParam, SeasonParam),
+    responses(
+        (status = 200, description = "IIHF standings of every team in the division", body = [IihfStatsI64]),
+        (status = 404, description = "The division does not exist", body = ErrorBody)
+    )
+)]
+async fn iihf_stats_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<IihfStatsI64>> {
+    let division = Division::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        division_iihf_stats(&server_config.db_pool, division.id, season.season, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/divisions/{id}/standings",
+    params(("id" = i32, Path, description = "Division id"), LangParam, SeasonParam),
+    responses(
+        (status = 200, description = "Teams of the division in order, with the IIHF tiebreakers applied", body = [Standing]),
+        (status = 404, description = "The division does not exist", body = ErrorBody)
+    )
+)]
+async fn standings_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<Standing>> {
+    let division = Division::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        division_standings(&server_config.db_pool, division.id, season.season, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/divisions/{id}/goalie-stats",
+    params(("id" = i32, Path, description = "Division id"), SeasonParam),
+    responses(
+        (status = 200, description = "Stats of every goalie who faced a shot in the division", body = [GoalieStats]),
+        (status = 404, description = "The division does not exist", body = ErrorBody)
+    )
+)]
+async fn goalie_stats_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<GoalieStats>> {
+    let division = Division::get(&server_config.db_pool, id, SupportedLanguage::English.into())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        division_goalie_stats(&server_config.db_pool, division.id, season.season).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/divisions/{id}/team-stats",
+    params(("id" = i32, Path, description = "Division id"), LangParam, SeasonParam),
+    responses(
+        (status = 200, description = "Goals and shots of every team over the division", body = [TeamStats]),
+        (status = 404, description = "The division does not exist", body = ErrorBody)
+    )
+)]
+async fn team_stats_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<TeamStats>> {
+    let division = Division::get(&server_config.db_pool, id, lang.lang_id())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        division_team_stats(&server_config.db_pool, division.id, season.season, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/divisions/{id}/player-stats",
+    params(("id" = i32, Path, description = "Division id"), SeasonParam),
+    responses(
+        (status = 200, description = "Points and shots of every player over the division", body = [PlayerStats]),
+        (status = 404, description = "The division does not exist", body = ErrorBody)
+    )
+)]
+async fn player_stats_for_division(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(season), _): SeasonQuery,
+) -> ApiResult<Vec<PlayerStats>> {
+    let division = Division::get(&server_config.db_pool, id, SupportedLanguage::English.into())
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(
+        division_player_stats(&server_config.db_pool, division.id, season.season).await?,
+    ))
+}
+
+/// Makes sure the game exists, so that an unknown game is a 404 instead of an empty list.
+async fn existing_game(server_config: &ServerState, id: i32, lang: i32) -> Result<Game, ApiError> {
+    Game::get(&server_config.db_pool, id, lang)
+        .await?
+        .ok_or(ApiError::NotFound)
+}
+
+/// Checks a game sent to the API with [`validate_game`]; the reasons are in `lang`.
+async fn checked_game(
+    server_config: &ServerState,
+    game: &InsertGame,
+    dressed_teams: &[i32],
+    lang: SupportedLanguage,
+) -> Result<(), ApiError> {
+    let teams = Team::by_division(&server_config.db_pool, game.division, lang.into()).await?;
+    let errors = validate_game(game, &teams, dressed_teams);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let reasons: Vec<String> = errors.into_iter().map(|key| lang.lookup(key)).collect();
+    Err(ApiError::Invalid(reasons.join(" ")))
+}
+
+#[utoipa::path(
+    post,
+    tag = "games",
+    path = "/api/v1/games",
+    params(LangParam),
+    request_body = InsertGame,
+    responses(
+        (status = 201, description = "The game was added", body = Game),
+        (status = 400, description = "The body or parameters are malformed", body = ErrorBody),
+        (status = 401, description = "Not logged in", body = ErrorBody),
+        (status = 403, description = "Only admins may add games", body = ErrorBody),
+        (status = 422, description = "The game breaks a scheduling rule; the reasons are in the requested language", body = ErrorBody)
+    )
+)]
+async fn create_game(
+    State(server_config): State<ServerState>,
+    _admin: AdminAuth,
+    WithRejection(Query(lang), _): LangQuery,
+    WithRejection(Json(new_game), _): GameBody,
+) -> Result<(StatusCode, Json<Game>), ApiError> {
+    checked_game(&server_config, &new_game, &[], lang.language()).await?;
+    let mut conn = server_config.db_pool.acquire().await?;
+    let id = Game::insert(&mut conn, &new_game).await?;
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok((StatusCode::CREATED, Json(game)))
+}
+
+#[utoipa::path(
+    put,
+    tag = "games",
+    path = "/api/v1/games/{id}",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    request_body = InsertGame,
+    responses(
+        (status = 200, description = "The teams, times and names of the game were changed", body = Game),
+        (status = 400, description = "The body or parameters are malformed", body = ErrorBody),
+        (status = 401, description = "Not logged in", body = ErrorBody),
+        (status = 403, description = "Only admins may change games", body = ErrorBody),
+        (status = 404, description = "The game does not exist", body = ErrorBody),
+        (status = 422, description = "The game breaks a scheduling rule; the reasons are in the requested language", body = ErrorBody)
+    )
+)]
+async fn update_game(
+    State(server_config): State<ServerState>,
+    _admin: AdminAuth,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+    WithRejection(Json(changed_game), _): GameBody,
+) -> ApiResult<Game> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    let dressed_teams = Game::dressed_teams(&server_config.db_pool, game.id).await?;
+    checked_game(&server_config, &changed_game, &dressed_teams, lang.language()).await?;
+    let mut conn = server_config.db_pool.acquire().await?;
+    Game::update(&mut conn, game.id, &changed_game).await?;
+    Ok(Json(existing_game(&server_config, game.id, lang.lang_id()).await?))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/box-score",
+    params(("id" = i32, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Points of every player in the game", body = [PlayerStats]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn box_score_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<Vec<PlayerStats>> {
+    let game = existing_game(&server_config, id, SupportedLanguage::English.into()).await?;
+    Ok(Json(game_box_score(&server_config.db_pool, game.id).await?))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/score",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    responses(
+        (status = 200, description = "Goals and shots of both teams", body = [TeamStats]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn score_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<TeamStats>> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok(Json(
+        game_score(&server_config.db_pool, game.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/goals",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    responses(
+        (status = 200, description = "Every goal of the game", body = [GoalDetails]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn goals_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<GoalDetails>> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok(Json(
+        game_goals(&server_config.db_pool, game.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/play-by-play",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    responses(
+        (status = 200, description = "Every shot of the game", body = [ShotDetails]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn play_by_play_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<ShotDetails>> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok(Json(
+        game_play_by_play(&server_config.db_pool, game.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/penalties",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    responses(
+        (status = 200, description = "Every penalty of the game", body = [PenaltyDetails]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn penalties_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<PenaltyDetails>> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok(Json(
+        game_penalties(&server_config.db_pool, game.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/shootout",
+    params(("id" = i32, Path, description = "Game id"), LangParam),
+    responses(
+        (status = 200, description = "Every shootout attempt of the game, in order", body = [ShootoutAttemptDetails]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn shootout_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<ShootoutAttemptDetails>> {
+    let game = existing_game(&server_config, id, lang.lang_id()).await?;
+    Ok(Json(
+        game_shootout(&server_config.db_pool, game.id, lang.lang_id()).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/games/{id}/goalie-stats",
+    params(("id" = i32, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Stats of every goalie who faced a shot in the game", body = [GoalieStats]),
+        (status = 404, description = "The game does not exist", body = ErrorBody)
+    )
+)]
+async fn goalie_stats_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<Vec<GoalieStats>> {
+    let game = existing_game(&server_config, id, SupportedLanguage::English.into()).await?;
+    Ok(Json(
+        game_goalie_stats(&server_config.db_pool, game.id).await?,
+    ))
+}
+
+async fn players_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<Vec<GamePlayer>> {
+    let game = existing_game(&server_config, id, SupportedLanguage::English.into()).await?;
+    Ok(Json(GamePlayer::by_game(&*server_config.db_pool, game.id).await?))
+}
+
+async fn periods_for_game(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<Vec<Period>> {
+    let game = existing_game(&server_config, id, SupportedLanguage::English.into()).await?;
+    Ok(Json(Period::by_game(&*server_config.db_pool, game.id).await?))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/players/{id}/lifetime-stats",
+    params(("id" = i32, Path, description = "Player id")),
+    responses(
+        (status = 200, description = "Points of the player over all games", body = PlayerStats),
+        (status = 404, description = "The player does not exist, or has not played a game", body = ErrorBody)
+    )
+)]
+async fn lifetime_stats_for_player(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<PlayerStats> {
+    let player = Player::get(&*server_config.db_pool, id).await?;
+    Ok(Json(
+        Player::lifetime_stats(&server_config.db_pool, player.id).await?,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/players/{id}/goalie-stats",
+    params(("id" = i32, Path, description = "Player id")),
+    responses(
+        (status = 200, description = "Stats of the player as a goalie over all games", body = GoalieStats),
+        (status = 404, description = "The player does not exist, or has never faced a shot", body = ErrorBody)
+    )
+)]
+async fn lifetime_goalie_stats_for_player(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+) -> ApiResult<GoalieStats> {
+    let player = Player::get(&*server_config.db_pool, id).await?;
+    Player::lifetime_goalie_stats(&server_config.db_pool, player.id)
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+#[utoipa::path(
+    get,
+    tag = "stats",
+    path = "/api/v1/players/{id}/latest-goals",
+    params(("id" = i32, Path, description = "Player id"), LangParam),
+    responses(
+        (status = 200, description = "The five latest goals of the player", body = [GoalDetails]),
+        (status = 404, description = "The player does not exist", body = ErrorBody)
+    )
+)]
+async fn latest_goals_for_player(
+    State(server_config): State<ServerState>,
+    WithRejection(Path(id), _): IdPath,
+    WithRejection(Query(lang), _): LangQuery,
+) -> ApiResult<Vec<GoalDetails>> {
+    let player = Player::get(&*server_config.db_pool, id).await?;
+    Ok(Json(
+        Player::latest_stats(&server_config.db_pool, player.id, lang.lang_id()).await?,
+    ))
+}
+
+async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// The JSON API; nested under `/api/v1`.
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .route("/openapi.json", get(openapi))
+        .route("/leagues", get(leagues))
+        .route("/leagues/:id", get(league))
+        .route("/leagues/:id/divisions", get(divisions_for_league))
+        .route("/leagues/:id/seasons", get(seasons_for_league))
+        .route("/leagues/:id/goalie-stats", get(goalie_stats_for_league))
+        .route("/divisions", get(divisions))
+        .route("/divisions/:id", get(division))
+        .route("/divisions/:id/games", get(games_for_division))
+        .route("/divisions/:id/points-system", get(points_system_for_division))
+        .route("/divisions/:id/iihf-stats", get(iihf_stats_for_division))
+        .route("/divisions/:id/standings", get(standings_for_division))
+        .route("/divisions/:id/goalie-stats", get(goalie_stats_for_division))
+        .route("/divisions/:id/team-stats", get(team_stats_for_division))
+        .route("/divisions/:id/player-stats", get(player_stats_for_division))
+        .route("/teams", get(teams))
+        .route("/teams/:id", get(team))
+        .route("/games", get(games).post(create_game))
+        .route("/games/:id", get(game).put(update_game))
+        .route("/games/:id/box-score", get(box_score_for_game))
+        .route("/games/:id/score", get(score_for_game))
+        .route("/games/:id/goals", get(goals_for_game))
+        .route("/games/:id/play-by-play", get(play_by_play_for_game))
+        .route("/games/:id/penalties", get(penalties_for_game))
+        .route("/games/:id/shootout", get(shootout_for_game))
+        .route("/games/:id/goalie-stats", get(goalie_stats_for_game))
+        .route("/games/:id/players", get(players_for_game))
+        .route("/games/:id/periods", get(periods_for_game))
+        .route("/players", get(players))
+        .route("/players/:id", get(player))
+        .route("/players/:id/lifetime-stats", get(lifetime_stats_for_player))
+        .route("/players/:id/goalie-stats", get(lifetime_goalie_stats_for_player))
+        .route("/players/:id/latest-goals", get(latest_goals_for_player))
+        .route("/game-players", get(game_players))
+        .route("/game-players/:id", get(game_player))
+        .route("/shots", get(shots))
+        .route("/shots/:id", get(shot))
+        .route("/periods", get(periods))
+        .route("/periods/:id", get(period))
+        .route("/penalties", get(penalties))
+        .route("/penalties/:id", get(penalty))
+        .route("/infractions", get(infractions))
+        .route("/infractions/:id", get(infraction))
+        .route("/seasons", get(seasons))
+        .route("/seasons/:id", get(season))
+        .route("/points-systems", get(points_systems))
+        .route("/points-systems/:id", get(points_system))
+        .route("/shootout-attempts", get(shootout_attempts))
+        .route("/shootout-attempts/:id", get(shootout_attempt))
+        .route("/player-classifications", get(player_classifications))
+        .route("/player-classifications/:id", get(player_classification))
+        .route("/classification-rules", get(classification_rules))
+        .route("/classification-rules/:id", get(classification_rule))
+        .fallback(|| async { ApiError::NotFound })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{goals_for_game, ApiDoc, ApiError, LangParam};
+    use crate::auth::AuthRejection;
+    use crate::languages::SupportedLanguage;
+    use crate::ServerState;
+    use axum::extract::{Path, Query, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum_extra::extract::cookie::Key;
+    use axum_extra::extract::WithRejection;
+    use sqlx::Executor;
+    use std::env;
+    use std::marker::PhantomData;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+    use utoipa::OpenApi;
+
+    /// The state of the server, with a single database connection that never commits;
+    /// whatever a test changes is rolled back when the state is dropped.
+    async fn rollback_state() -> ServerState {
+        let db_url = env::var("DATABASE_URL")
+            .expect("DATABASE_URL environment variable must be set to run tests.");
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .after_connect(|conn, _meta| {
+                Box::pin(async move {
+                    conn.execute("BEGIN;").await?;
+                    Ok(())
+                })
+            })
+            .connect(&db_url)
+            .await
+            .expect("Active database connection must be made");
+        ServerState {
+            db_pool: Arc::new(pool),
+            cookie_key: Key::generate(),
+            default_lang: SupportedLanguage::English,
+            shot_events: broadcast::channel(1).0,
+            announcements: broadcast::channel(1).0,
+        }
+    }
+
+    #[test]
+    fn check_game_goals() {
+        tokio_test::block_on(async move {
+            let state = rollback_state().await;
+            // Hoyem (#3) gets the second assist on Dorn's goal in game 2
+            sqlx::query("UPDATE shots SET assistant_second=31 WHERE id=31;")
+                .execute(&*state.db_pool)
+                .await
+                .unwrap();
+            let goals = goals_for_game(
+                State(state),
+                WithRejection(Path(2), PhantomData),
+                WithRejection(
+                    Query(LangParam {
+                        lang: Some(SupportedLanguage::French),
+                    }),
+                    PhantomData,
+                ),
+            )
+            .await
+            .unwrap()
+            .0;
+            assert_eq!(goals.len(), 1);
+            let goal = goals.get(0).unwrap();
+            assert_eq!(goal.team_name, "bulle", "Team names are in the requested language.");
+            assert_eq!(goal.player_last_name, "Dorn");
+            assert_eq!(goal.first_assist_last_name.as_deref(), Some("Scanlon"));
+            assert_eq!(goal.second_assist_last_name.as_deref(), Some("Hoyem"));
+            assert_eq!(goal.second_assist_number, Some(3));
+        })
+    }
+
+    #[test]
+    fn check_error_status() {
+        assert_eq!(
+            ApiError::from(sqlx::Error::RowNotFound).into_response().status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            ApiError::BadRequest("Invalid id.".to_string()).into_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            ApiError::Invalid("The home and away teams must be different.".to_string())
+                .into_response()
+                .status(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            ApiError::from(sqlx::Error::PoolTimedOut).into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            ApiError::from(AuthRejection::NotLoggedIn(SupportedLanguage::French))
+                .into_response()
+                .status(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            ApiError::from(AuthRejection::MissingRole(SupportedLanguage::French))
+                .into_response()
+                .status(),
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    /// If this fails, a type in the API changed: regenerate `openapi.json` with
+    /// `UPDATE_OPENAPI=1 cargo test check_openapi_is_up_to_date`, and review the difference.
+    #[test]
+    fn check_openapi_is_up_to_date() {
+        let generated = ApiDoc::openapi()
+            .to_pretty_json()
+            .expect("The OpenAPI document must serialize.");
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json");
+        if std::env::var_os("UPDATE_OPENAPI").is_some() {
+            std::fs::write(path, &generated).unwrap();
+        }
+        let checked_in = std::fs::read_to_string(path).unwrap();
+        assert_eq!(
+            checked_in.trim_end(),
+            generated.trim_end(),
+            "openapi.json is out of date."
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use crate::errors::AppError;
+use crate::languages::SupportedLanguage;
+use crate::model::{Role, User};
+use crate::ServerState;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::cookie::{Cookie, Key, SameSite, SignedCookieJar};
+use ormx::Table;
+use sqlx::PgPool;
+use std::marker::PhantomData;
+
+/// The name of the signed cookie which holds the id of the logged in user.
+pub const SESSION_COOKIE: &str = "ibihf_session";
+
+/// Looks up the user by name and checks the password against the stored bcrypt hash.
+/// Returns `None` if the user does not exist or the password does not match.
+pub async fn verify_login(
+    pool: &PgPool,
+    user_name: &str,
+    password: &str,
+) -> Result<Option<User>, sqlx::Error> {
+    let Some(user) = User::by_user_name(pool, user_name).await? else {
+        return Ok(None);
+    };
+    let password = password.to_string();
+    let pass_hash = user.pass_hash.clone();
+    // bcrypt is intentionally slow; do not block the async runtime while it runs.
+    let verified = tokio::task::spawn_blocking(move || bcrypt::verify(password, &pass_hash))
+        .await
+        .expect("The password verification task panicked.")
+        .unwrap_or(false);
+    Ok(verified.then_some(user))
+}
+
+/// Hashes a password with bcrypt, for storing in `users.pass_hash`.
+pub async fn hash_password(password: String) -> Result<String, bcrypt::BcryptError> {
+    tokio::task::spawn_blocking(move || bcrypt::hash(password, bcrypt::DEFAULT_COST))
+        .await
+        .expect("The password hashing task panicked.")
+}
+
+/// Returns the id of the logged in user, if there is a valid session cookie.
+pub fn session_user_id(jar: &SignedCookieJar) -> Option<i32> {
+    jar.get(SESSION_COOKIE)
+        .and_then(|cookie| cookie.value().parse().ok())
+}
+
+/// Adds a session cookie for the given user to the jar.
+pub fn start_session(jar: SignedCookieJar, user: &User) -> SignedCookieJar {
+    jar.add(session_cookie(user.id.to_string()))
+}
+
+/// Removes the session cookie from the jar.
+pub fn end_session(jar: SignedCookieJar) -> SignedCookieJar {
+    jar.remove(session_cookie(String::new()))
+}
+
+fn session_cookie(value: String) -> Cookie<'static> {
+    Cookie::build(SESSION_COOKIE, value)
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .finish()
+}
+
+/// A role from the `roles` table which a handler may require.
+pub trait RequiredRole {
+    /// The id of the role in the `roles` table.
+    const ROLE_ID: i32;
+}
+
+/// Can do anything; passes every role check.
+pub struct Admin;
+impl RequiredRole for Admin {
+    const ROLE_ID: i32 = 1;
+}
+/// Can review records entered by others.
+#[allow(dead_code)]
+pub struct Reviewer;
+impl RequiredRole for Reviewer {
+    const ROLE_ID: i32 = 2;
+}
+/// A read-only user.
+#[allow(dead_code)]
+pub struct ReadOnly;
+impl RequiredRole for ReadOnly {
+    const ROLE_ID: i32 = 3;
+}
+/// Can record game events for a league, or for every league; see [`Authorized::has_role_in`].
+pub struct Scorekeeper;
+impl RequiredRole for Scorekeeper {
+    const ROLE_ID: i32 = 4;
+}
+
+/// An extractor for a logged in user which has the role `R` (or is an admin).
+/// Add it to a handler's arguments to guard the route; any other request gets a localized 403 page.
+/// The JSON API wraps it in `WithRejection` to answer with an [`crate::api::ApiError`] instead.
+pub struct Authorized<R> {
+    pub user: User,
+    pub roles: Vec<Role>,
+    role: PhantomData<fn() -> R>,
+}
+
+/// Why a request was not [`Authorized`]; as a response, it is the localized 403 or 500 page.
+#[derive(Debug)]
+pub enum AuthRejection {
+    /// There is no valid session.
+    NotLoggedIn(SupportedLanguage),
+    /// The user does not have the role.
+    MissingRole(SupportedLanguage),
+    /// The user or their roles could not be looked up.
+    Database(SupportedLanguage, sqlx::Error),
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        match self {
+            AuthRejection::NotLoggedIn(lang) | AuthRejection::MissingRole(lang) => {
+                crate::forbidden_page(lang)
+            }
+            AuthRejection::Database(lang, e) => AppError::Database(lang, e).into_response(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RequiredRole> FromRequestParts<ServerState> for Authorized<R> {
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &ServerState,
+    ) -> Result<Self, Self::Rejection> {
+        let lang = SupportedLanguage::from_path(parts.uri.path()).unwrap_or(state.default_lang);
+        let jar = SignedCookieJar::<Key>::from_headers(&parts.headers, state.cookie_key.clone());
+        let Some(user_id) = session_user_id(&jar) else {
+            return Err(AuthRejection::NotLoggedIn(lang));
+        };
+        let user = match User::get(&*state.db_pool, user_id).await {
+            Ok(user) => user,
+            // the user was deleted since the session started
+            Err(sqlx::Error::RowNotFound) => return Err(AuthRejection::NotLoggedIn(lang)),
+            Err(e) => return Err(AuthRejection::Database(lang, e)),
+        };
+        let roles = Role::by_user(&state.db_pool, user.id, lang.into())
+            .await
+            .map_err(|e| AuthRejection::Database(lang, e))?;
+        if !roles
+            .iter()
+            .any(|role| role.id == R::ROLE_ID || role.id == Admin::ROLE_ID)
+        {
+            return Err(AuthRejection::MissingRole(lang));
+        }
+        Ok(Authorized {
+            user,
+            roles,
+            role: PhantomData,
+        })
+    }
+}
+
+impl<R: RequiredRole> Authorized<R> {
+    /// Whether the user has the role in a league; a role given without a league is for every league.
+    /// Admins have every role in every league.
+    pub async fn has_role_in<'e>(
+        &self,
+        executor: impl sqlx::PgExecutor<'e>,
+        league_id: i32,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+SELECT EXISTS (
+  SELECT users_roles.id
+  FROM users_roles
+  WHERE users_roles.user_id=$1
+    AND (users_roles.role=$2
+    OR (users_roles.role=$3
+    AND (users_roles.league IS NULL OR users_roles.league=$4)))
+);
+"#,
+        )
+        .bind(self.user.id)
+        .bind(Admin::ROLE_ID)
+        .bind(R::ROLE_ID)
+        .bind(league_id)
+        .fetch_one(executor)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::{hash_password, verify_login, Admin, Authorized, RequiredRole, Scorekeeper};
+    use crate::languages::SupportedLanguage;
+    use crate::model::{Role, User};
+    use ormx::Table;
+    use std::env;
+    use std::marker::PhantomData;
+
+    #[test]
+    fn check_admin_login() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let user = verify_login(&pool, "admin", "admin").await.unwrap();
+            assert!(user.is_some(), "The default admin/admin login should work.");
+            assert_eq!(user.unwrap().user_name, "admin");
+        })
+    }
+
+    #[test]
+    fn check_wrong_password() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let user = verify_login(&pool, "admin", "not-the-password").await.unwrap();
+            assert!(user.is_none(), "A wrong password must not log in.");
+            let user = verify_login(&pool, "nobody", "admin").await.unwrap();
+            assert!(user.is_none(), "An unknown user must not log in.");
+        })
+    }
+
+    #[test]
+    fn check_hash_password() {
+        tokio_test::block_on(async move {
+            let hash = hash_password("hunter2".to_string()).await.unwrap();
+            assert!(bcrypt::verify("hunter2", &hash).unwrap());
+            assert!(!bcrypt::verify("hunter3", &hash).unwrap());
+        })
+    }
+
+    #[test]
+    fn check_admin_roles() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let roles = Role::by_user(&pool, 1, SupportedLanguage::French.into())
+                .await
+                .unwrap();
+            let admin = roles.iter().find(|role| role.id == Admin::ROLE_ID);
+            assert!(admin.is_some(), "The default admin should have the admin role.");
+            assert_eq!(admin.unwrap().name.as_ref().unwrap(), "administrateur");
+        })
+    }
+
+    #[test]
+    fn check_league_scorekeeper() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let mut tx = pool.begin().await.unwrap();
+            let user: User = sqlx::query_as(
+                "INSERT INTO users (user_name, pass_hash) VALUES ('league-scorekeeper', '') RETURNING *;",
+            )
+            .fetch_one(&mut tx)
+            .await
+            .unwrap();
+            sqlx::query("INSERT INTO users_roles (user_id, role, league) VALUES ($1, $2, 1);")
+                .bind(user.id)
+                .bind(Scorekeeper::ROLE_ID)
+                .execute(&mut tx)
+                .await
+                .unwrap();
+            let scorekeeper = Authorized::<Scorekeeper> {
+                user,
+                roles: Vec::new(),
+                role: PhantomData,
+            };
+            assert!(scorekeeper.has_role_in(&mut tx, 1).await.unwrap());
+            assert!(
+                !scorekeeper.has_role_in(&mut tx, 2).await.unwrap(),
+                "A league's scorekeeper does not keep score in other leagues."
+            );
+            let admin = Authorized::<Scorekeeper> {
+                user: User::get(&mut tx, 1).await.unwrap(),
+                roles: Vec::new(),
+                role: PhantomData,
+            };
+            assert!(admin.has_role_in(&mut tx, 2).await.unwrap(), "Admins keep score in every league.");
+            tx.rollback().await.unwrap();
+        })
+    }
+
+    /// A simple function to connect to the database.
+    async fn db_connect() -> sqlx::PgPool {
+        let db_url = env::var("DATABASE_URL")
+            .expect("DATABASE_URL environment variable must be set to run tests.");
+        sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&db_url)
+            .await
+            .expect("Active database connection must be made")
+    }
+}
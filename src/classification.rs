@@ -0,0 +1,209 @@
+use crate::model::{ClassificationRule, STAFF_POSITIONS};
+use crate::views::RosterPlayer;
+
+/// The classification of fully sighted players; they may only play goalie.
+pub const SIGHTED: &str = "SIGHTED";
+
+/// Something in a team's lineup for a game which breaks the classification rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineupProblem {
+    /// The player has no classification on the day of the game.
+    Unclassified {
+        team_name: String,
+        player_number: i32,
+        last_name: String,
+    },
+    /// A sighted player is dressed at another position than goalie.
+    SightedSkater {
+        team_name: String,
+        player_number: i32,
+        last_name: String,
+    },
+    /// The team dressed fewer players of a classification than the rule requires.
+    TooFew {
+        team_name: String,
+        classification: String,
+        count: usize,
+        min_players: i32,
+    },
+    /// The team dressed more players of a classification than the rule allows.
+    TooMany {
+        team_name: String,
+        classification: String,
+        count: usize,
+        max_players: i32,
+    },
+}
+
+impl LineupProblem {
+    /// The key of the problem's description in the translations.
+    pub fn key(&self) -> &'static str {
+        match self {
+            LineupProblem::Unclassified { .. } => "lineup-unclassified",
+            LineupProblem::SightedSkater { .. } => "lineup-sighted-skater",
+            LineupProblem::TooFew { .. } => "lineup-too-few",
+            LineupProblem::TooMany { .. } => "lineup-too-many",
+        }
+    }
+}
+
+/// Checks the lineups of a game against the classification rules of its division.
+/// Teams without any dressed player are not checked, as their lineup has not been entered yet.
+pub fn validate(roster: &[RosterPlayer], rules: &[ClassificationRule]) -> Vec<LineupProblem> {
+    let mut problems = Vec::new();
+    let players: Vec<&RosterPlayer> = roster
+        .iter()
+        .filter(|player| !STAFF_POSITIONS.contains(&player.position_short_name.as_str()))
+        .collect();
+    for player in &players {
+        match player.classification.as_deref() {
+            None => problems.push(LineupProblem::Unclassified {
+                team_name: player.team_name.clone(),
+                player_number: player.player_number,
+                last_name: player.last_name.clone(),
+            }),
+            Some(SIGHTED) if player.position_short_name != "G" => {
+                problems.push(LineupProblem::SightedSkater {
+                    team_name: player.team_name.clone(),
+                    player_number: player.player_number,
+                    last_name: player.last_name.clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    let mut teams: Vec<(i32, &str)> = players
+        .iter()
+        .map(|player| (player.team_id, player.team_name.as_str()))
+        .collect();
+    teams.sort_unstable();
+    teams.dedup();
+    for (team_id, team_name) in teams {
+        for rule in rules {
+            let count = players
+                .iter()
+                .filter(|player| {
+                    player.team_id == team_id
+                        && player.classification.as_deref() == Some(rule.classification.as_str())
+                })
+                .count();
+            let count_i32 = i32::try_from(count).unwrap_or(i32::MAX);
+            if let Some(min_players) = rule.min_players.filter(|min| count_i32 < *min) {
+                problems.push(LineupProblem::TooFew {
+                    team_name: team_name.to_string(),
+                    classification: rule.classification.clone(),
+                    count,
+                    min_players,
+                });
+            }
+            if let Some(max_players) = rule.max_players.filter(|max| count_i32 > *max) {
+                problems.push(LineupProblem::TooMany {
+                    team_name: team_name.to_string(),
+                    classification: rule.classification.clone(),
+                    count,
+                    max_players,
+                });
+            }
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::classification::{validate, LineupProblem, SIGHTED};
+    use crate::model::ClassificationRule;
+    use crate::views::RosterPlayer;
+
+    fn player(id: i32, team_id: i32, position: &str, classification: Option<&str>) -> RosterPlayer {
+        RosterPlayer {
+            id,
+            player_id: id,
+            player_number: id,
+            first_names: "First".to_string(),
+            last_name: format!("Player {id}"),
+            team_id,
+            team_name: format!("Team {team_id}"),
+            position_short_name: position.to_string(),
+            classification: classification.map(str::to_string),
+        }
+    }
+
+    fn rule(classification: &str, min_players: Option<i32>, max_players: Option<i32>) -> ClassificationRule {
+        ClassificationRule {
+            id: 1,
+            division: 1,
+            classification: classification.to_string(),
+            min_players,
+            max_players,
+        }
+    }
+
+    #[test]
+    fn valid_lineup() {
+        let roster = vec![
+            player(1, 1, "G", Some(SIGHTED)),
+            player(2, 1, "C", Some("B1")),
+            player(3, 1, "D", Some("B3")),
+            player(4, 1, "HC", None),
+        ];
+        let rules = vec![rule("B1", Some(1), None), rule(SIGHTED, None, Some(2))];
+        assert_eq!(validate(&roster, &rules), Vec::new());
+    }
+
+    #[test]
+    fn players_must_be_classified() {
+        let roster = vec![player(1, 1, "C", None), player(2, 1, "AC", None)];
+        assert_eq!(
+            validate(&roster, &[]),
+            vec![LineupProblem::Unclassified {
+                team_name: "Team 1".to_string(),
+                player_number: 1,
+                last_name: "Player 1".to_string(),
+            }],
+            "Coaches are not classified."
+        );
+    }
+
+    #[test]
+    fn only_goalies_may_be_sighted() {
+        let roster = vec![player(1, 1, "G", Some(SIGHTED)), player(2, 1, "R", Some(SIGHTED))];
+        assert_eq!(
+            validate(&roster, &[]),
+            vec![LineupProblem::SightedSkater {
+                team_name: "Team 1".to_string(),
+                player_number: 2,
+                last_name: "Player 2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn rules_apply_to_each_team() {
+        let roster = vec![
+            player(1, 1, "C", Some("B1")),
+            player(2, 1, "D", Some("B3")),
+            player(3, 1, "L", Some("B3")),
+            player(4, 2, "C", Some("B1")),
+            player(5, 2, "D", Some("B1")),
+        ];
+        let rules = vec![rule("B1", Some(2), None), rule("B3", None, Some(1))];
+        assert_eq!(
+            validate(&roster, &rules),
+            vec![
+                LineupProblem::TooFew {
+                    team_name: "Team 1".to_string(),
+                    classification: "B1".to_string(),
+                    count: 1,
+                    min_players: 2,
+                },
+                LineupProblem::TooMany {
+                    team_name: "Team 1".to_string(),
+                    classification: "B3".to_string(),
+                    count: 2,
+                    max_players: 1,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::auth;
+use crate::db::MIGRATOR;
+use crate::languages::SupportedLanguage;
+use crate::model::{InsertUser, Role, User};
+use clap::{Parser, Subcommand};
+use ormx::Insert;
+use sqlx::migrate::Migrate;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::BufRead;
+
+type CliResult = Result<(), Box<dyn Error>>;
+
+/// The IBIHF statistics server.
+#[derive(Parser, Debug)]
+#[command(version = crate::VERSION, about)]
+pub struct Cli {
+    /// Runs the web server if no command is given.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Runs the web server.
+    Serve,
+    /// Applies, reverts or lists the database migrations built into this binary.
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateCommand,
+    },
+    /// Adds a user who can log in.
+    CreateUser {
+        user_name: String,
+        /// A role to give the user, by its English name (admin, reviewer, user or scorekeeper); may be repeated.
+        #[arg(long = "role")]
+        roles: Vec<String>,
+        /// Limits the roles to the league with this id, like for the scorekeeper of one league; without it, they are for every league.
+        #[arg(long)]
+        league: Option<i32>,
+        /// Reads the password from the first line of standard input instead of asking for it.
+        #[arg(long)]
+        password_stdin: bool,
+    },
+    /// Checks that the database can be reached and that every migration has been applied.
+    CheckDb,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MigrateCommand {
+    /// Applies every migration which has not been applied yet.
+    Up,
+    /// Reverts the latest migration, or every migration after `--target`.
+    Down {
+        /// The version to go back to; 0 reverts everything.
+        #[arg(long)]
+        target: Option<i64>,
+    },
+    /// Lists every migration and whether it has been applied.
+    Status,
+}
+
+/// The versions of the migrations applied to the database, with their checksums.
+async fn applied_migrations(pool: &PgPool) -> Result<HashMap<i64, Vec<u8>>, Box<dyn Error>> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    Ok(conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| (migration.version, migration.checksum.into_owned()))
+        .collect())
+}
+
+pub async fn migrate(pool: &PgPool, action: MigrateCommand) -> CliResult {
+    match action {
+        MigrateCommand::Up => {
+            MIGRATOR.run(pool).await?;
+            println!("All migrations have been applied.");
+        }
+        MigrateCommand::Down { target } => {
+            let target = match target {
+                Some(target) => target,
+                None => {
+                    // the latest migration is reverted by going back to the one before it
+                    let mut applied: Vec<i64> = applied_migrations(pool).await?.into_keys().collect();
+                    applied.sort_unstable();
+                    applied.pop();
+                    applied.pop().unwrap_or(0)
+                }
+            };
+            MIGRATOR.undo(pool, target).await?;
+            println!("Reverted every migration after version {target}.");
+        }
+        MigrateCommand::Status => {
+            let applied = applied_migrations(pool).await?;
+            for migration in MIGRATOR
+                .iter()
+                .filter(|migration| !migration.migration_type.is_down_migration())
+            {
+                let status = match applied.get(&migration.version) {
+                    Some(checksum) if checksum.as_slice() == &*migration.checksum => "applied",
+                    Some(_) => "changed since it was applied",
+                    None => "pending",
+                };
+                println!("{} {:<48} {status}", migration.version, migration.description);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn check_db(pool: &PgPool) -> CliResult {
+    sqlx::query("SELECT 1;").execute(pool).await?;
+    println!("The database can be reached.");
+    let applied = applied_migrations(pool).await?;
+    let pending: Vec<_> = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .filter(|migration| !applied.contains_key(&migration.version))
+        .collect();
+    if pending.is_empty() {
+        println!("Every migration has been applied.");
+        Ok(())
+    } else {
+        for migration in &pending {
+            println!("Pending: {} {}", migration.version, migration.description);
+        }
+        Err(format!("{} migrations have not been applied; run `ibihf migrate up`.", pending.len()).into())
+    }
+}
+
+fn read_password(from_stdin: bool) -> Result<String, Box<dyn Error>> {
+    if from_stdin {
+        let mut line = String::new();
+        std::io::stdin().lock().read_line(&mut line)?;
+        return Ok(line.trim_end_matches(['\r', '\n']).to_string());
+    }
+    let password = rpassword::prompt_password("Password: ")?;
+    if password != rpassword::prompt_password("Password again: ")? {
+        return Err("The passwords do not match.".into());
+    }
+    Ok(password)
+}
+
+pub async fn create_user(
+    pool: &PgPool,
+    user_name: String,
+    role_names: Vec<String>,
+    league: Option<i32>,
+    password_stdin: bool,
+) -> CliResult {
+    if User::by_user_name(pool, &user_name).await?.is_some() {
+        return Err(format!("A user named {user_name} already exists.").into());
+    }
+    let roles = Role::all(pool, SupportedLanguage::English.into()).await?;
+    let mut role_ids = Vec::new();
+    for role_name in &role_names {
+        match roles
+            .iter()
+            .find(|role| role.name.as_deref().is_some_and(|name| name.eq_ignore_ascii_case(role_name)))
+        {
+            Some(role) => role_ids.push(role.id),
+            None => return Err(format!("There is no role named {role_name}.").into()),
+        }
+    }
+    let password = read_password(password_stdin)?;
+    if password.is_empty() {
+        return Err("The password can not be empty.".into());
+    }
+    let pass_hash = auth::hash_password(password).await?;
+    let mut tx = pool.begin().await?;
+    let user = InsertUser { user_name, pass_hash }.insert(&mut tx).await?;
+    for role_id in role_ids {
+        sqlx::query("INSERT INTO users_roles (user_id, role, league) VALUES ($1, $2, $3);")
+            .bind(user.id)
+            .bind(role_id)
+            .bind(league)
+            .execute(&mut tx)
+            .await?;
+    }
+    tx.commit().await?;
+    println!("Created user {} with id {}.", user.user_name, user.id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Command, MigrateCommand};
+    use clap::{CommandFactory, Parser};
+
+    #[test]
+    fn check_cli() {
+        Cli::command().debug_assert();
+        let cli = Cli::parse_from(["ibihf", "migrate", "down", "--target", "0"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Migrate {
+                action: MigrateCommand::Down { target: Some(0) }
+            })
+        ));
+        let cli = Cli::parse_from(["ibihf", "create-user", "tait", "--role", "admin", "--role", "scorekeeper"]);
+        assert!(matches!(cli.command, Some(Command::CreateUser { roles, league: None, .. }) if roles.len() == 2));
+        let cli = Cli::parse_from(["ibihf", "create-user", "tait", "--role", "scorekeeper", "--league", "1"]);
+        assert!(matches!(cli.command, Some(Command::CreateUser { league: Some(1), .. })));
+        assert!(Cli::parse_from(["ibihf"]).command.is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::languages::SupportedLanguage;
+use axum::response::{IntoResponse, Response};
+
+/// An error from a page handler; it is shown as a localized error page.
+#[derive(Debug)]
+pub enum AppError {
+    /// The row asked for does not exist.
+    NotFound(SupportedLanguage),
+    /// The user may not do this here, like a scorekeeper of another league.
+    Forbidden(SupportedLanguage),
+    /// Anything else which went wrong with the database.
+    Database(SupportedLanguage, sqlx::Error),
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        match self {
+            AppError::NotFound(lang) => crate::not_found_page(lang),
+            AppError::Forbidden(lang) => crate::forbidden_page(lang),
+            AppError::Database(lang, e) => {
+                log::error!("Database error: {e}");
+                crate::server_error_page(lang)
+            }
+        }
+    }
+}
+
+/// Turns database results into an [`AppError`] with the language of the page.
+pub trait OrAppError<T> {
+    fn or_app_error(self, lang: SupportedLanguage) -> Result<T, AppError>;
+}
+impl<T> OrAppError<T> for Result<T, sqlx::Error> {
+    fn or_app_error(self, lang: SupportedLanguage) -> Result<T, AppError> {
+        self.map_err(|e| match e {
+            sqlx::Error::RowNotFound => AppError::NotFound(lang),
+            e => AppError::Database(lang, e),
+        })
+    }
+}
+
+/// Turns a missing row into [`AppError::NotFound`].
+pub trait OrNotFound<T> {
+    fn or_not_found(self, lang: SupportedLanguage) -> Result<T, AppError>;
+}
+impl<T> OrNotFound<T> for Option<T> {
+    fn or_not_found(self, lang: SupportedLanguage) -> Result<T, AppError> {
+        self.ok_or(AppError::NotFound(lang))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::{AppError, OrAppError, OrNotFound};
+    use crate::languages::SupportedLanguage;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn check_error_status() {
+        let not_found: Result<i32, sqlx::Error> = Err(sqlx::Error::RowNotFound);
+        let response = not_found
+            .or_app_error(SupportedLanguage::French)
+            .unwrap_err()
+            .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let missing: Option<i32> = None;
+        let response = missing
+            .or_not_found(SupportedLanguage::English)
+            .unwrap_err()
+            .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = AppError::Forbidden(SupportedLanguage::French).into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response =
+            AppError::Database(SupportedLanguage::English, sqlx::Error::PoolTimedOut).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
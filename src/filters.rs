@@ -4,7 +4,10 @@
 // We must always take references, even when it's not technically the fastest thing to do.
 // This sometimes also causes a clippy warning.
 #![allow(clippy::trivially_copy_pass_by_ref)]
+use crate::classification::LineupProblem;
+use crate::standings::Tiebreak;
 use crate::{GoalDetails, Player, ShotDetails, SupportedLanguage};
+use askama::i18n::Locale;
 
 pub fn seconds_as_time(secs: &i32) -> ::askama::Result<String> {
     let minutes = secs / 60;
@@ -97,3 +100,84 @@ pub fn nullable<T: std::fmt::Display>(ot: &Option<T>) -> ::askama::Result<String
         None => Ok("NULL".to_string()),
     }
 }
+/// Localizes a goal's strength (`EV`, `PP`, `SH` or `EN`); see [`crate::views::GoalDetails::strength`].
+pub fn strength(strength: &str, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    Ok(lang.lookup(&format!("strength-{strength}")))
+}
+/// Localizes a position by its short name, like `C` or `G`.
+pub fn position(short_name: &str, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    Ok(lang.lookup(&format!("position-{short_name}")))
+}
+/// Localizes the result of a game for a team; see the `game_outcome` function.
+pub fn outcome(outcome: &str, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    Ok(lang.lookup(&format!("outcome-{outcome}")))
+}
+/// Localizes a sight classification, like `B1` or `SIGHTED`.
+pub fn classification(classification: &str, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    Ok(lang.lookup(&format!("classification-{classification}")))
+}
+/// Describes a problem with a lineup; see [`crate::classification::validate`].
+pub fn lineup_problem(problem: &LineupProblem, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    let args = match problem {
+        LineupProblem::Unclassified {
+            team_name,
+            player_number,
+            last_name,
+        }
+        | LineupProblem::SightedSkater {
+            team_name,
+            player_number,
+            last_name,
+        } => hashmap_macro::hashmap![
+            "team" => team_name.clone().into(),
+            "number" => (*player_number).into(),
+            "name" => last_name.clone().into()
+        ],
+        LineupProblem::TooFew {
+            team_name,
+            classification: class,
+            count,
+            min_players: limit,
+        }
+        | LineupProblem::TooMany {
+            team_name,
+            classification: class,
+            count,
+            max_players: limit,
+        } => hashmap_macro::hashmap![
+            "team" => team_name.clone().into(),
+            "classification" => classification(class, lang)?.into(),
+            "count" => (*count).into(),
+            "limit" => (*limit).into()
+        ],
+    };
+    Ok(Into::<Locale>::into(*lang)
+        .translate(problem.key(), args)
+        .expect("Unable to find a key of a lineup problem."))
+}
+/// Describes what broke a tie in the standings; nothing if the team was not tied.
+pub fn tiebreak(tiebreak: &Option<Tiebreak>, lang: &SupportedLanguage) -> ::askama::Result<String> {
+    Ok(tiebreak.map_or_else(String::new, |tiebreak| lang.lookup(tiebreak.key())))
+}
+pub fn percentage(value: &Option<f64>) -> ::askama::Result<String> {
+    match value {
+        Some(value) => Ok(format!("{value:.1}")),
+        None => Ok("-".to_string()),
+    }
+}
+/// Formats a save percentage the way hockey does, like `.917`.
+pub fn save_percentage(value: &Option<f64>) -> ::askama::Result<String> {
+    match value {
+        Some(value) => {
+            let formatted = format!("{value:.3}");
+            Ok(formatted.strip_prefix('0').unwrap_or(&formatted).to_string())
+        }
+        None => Ok("-".to_string()),
+    }
+}
+pub fn average(value: &Option<f64>) -> ::askama::Result<String> {
+    match value {
+        Some(value) => Ok(format!("{value:.2}")),
+        None => Ok("-".to_string()),
+    }
+}
@@ -1,12 +1,20 @@
 use crate::{SupportedLanguage, Locale, VERSION};
+use crate::model::{
+    Game, Infraction, InsertGame, InsertGamePlayer, InsertPenalty, InsertShootoutAttempt, InsertShot,
+    Player, Position, Team, STAFF_POSITIONS,
+};
+use crate::views::{LeaderSort, PeriodDetails, RosterPlayer, ShootoutAttemptDetails};
 use axum::Form;
 use askama_axum::Template;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
 
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Login {
-  username: String,
-  password: String,
+  pub username: String,
+  pub password: String,
 }
 
 impl std::fmt::Display for Login {
@@ -14,13 +22,1010 @@ impl std::fmt::Display for Login {
     write!(formatter, r#"
   <form method="POST">
     <label for="uname">Username</label>
-    <input id="uname" type="text" name="username"/>
+    <input id="uname" type="text" name="username" value="{}"/>
     <br/>
     <label for="pass">Password</label>
     <input id="pass" type="password" name="password"/>
     <input type="submit"/>
-  </forn> 
-"#);
+  </form>
+"#, askama::filters::MarkupDisplay::new_unsafe(&self.username, askama::Html))?;
     Ok(())
   }
 }
+
+/// Treats an empty form field (like an unselected `<select>`) as `None`.
+fn empty_string_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let opt = Option::<String>::deserialize(de)?;
+    match opt.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// The `?season=` parameter of the league and division pages; without it, the current season is shown.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SeasonSelect {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub season: Option<i32>,
+}
+
+/// The query of the scoring leaders pages, like `?season=1&min_games=2&sort=goals`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct LeadersQuery {
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub season: Option<i32>,
+    /// Only players dressed for at least this many games are ranked.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub min_games: Option<i64>,
+    #[serde(default)]
+    pub sort: LeaderSort,
+}
+
+/// Parses a time in the form of `MM:SS` into seconds; the inverse of `filters::seconds_as_time`.
+pub fn parse_time(time: &str) -> Option<i32> {
+    let (minutes, seconds) = time.trim().split_once(':')?;
+    let minutes: i32 = minutes.parse().ok()?;
+    let seconds: i32 = seconds.parse().ok()?;
+    if minutes < 0 || !(0..60).contains(&seconds) {
+        return None;
+    }
+    Some(minutes * 60 + seconds)
+}
+
+/// Checks that the period is part of the game, and the time is within the period.
+/// Returns the time in seconds if it is valid; otherwise, adds the translation key of the problem to `errors`.
+fn validate_period_time(
+    periods: &[PeriodDetails],
+    period: i32,
+    period_time: &str,
+    errors: &mut Vec<&'static str>,
+) -> Option<i32> {
+    let period = periods.iter().find(|p| p.id == period);
+    match (period, parse_time(period_time)) {
+        (None, _) => errors.push("error-period-not-in-game"),
+        (_, None) => errors.push("error-invalid-time"),
+        (Some(period), Some(time)) if time > period.period_length => {
+            errors.push("error-time-past-period");
+        }
+        (Some(_), Some(time)) => return Some(time),
+    }
+    None
+}
+
+/// A shot entered from the scorekeeping page.
+/// All player fields are `game_players` ids.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NewShot {
+    pub shooter: i32,
+    pub goalie: i32,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub blocker: Option<i32>,
+    #[serde(default)]
+    pub on_net: bool,
+    #[serde(default)]
+    pub goal: bool,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub assistant: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub assistant_second: Option<i32>,
+    pub period: i32,
+    pub period_time: String,
+    /// Defaults to [`FULL_STRENGTH`] when left empty.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub shooter_skaters: Option<i32>,
+    /// Defaults to [`FULL_STRENGTH`] when left empty.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub opponent_skaters: Option<i32>,
+    #[serde(default)]
+    pub empty_net: bool,
+}
+
+/// The number of skaters a team has on the ice when nobody is in the penalty box.
+pub const FULL_STRENGTH: i32 = 5;
+/// Penalties can never leave a team with fewer skaters than this.
+pub const MIN_SKATERS: i32 = 3;
+
+impl NewShot {
+    /// Checks the shot against the game's roster and periods.
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        roster: &[RosterPlayer],
+        periods: &[PeriodDetails],
+    ) -> Result<InsertShot, Vec<&'static str>> {
+        let mut errors = Vec::new();
+        let find = |id: i32| roster.iter().find(|player| player.id == id);
+        let shooter = find(self.shooter);
+        let goalie = find(self.goalie);
+        if shooter.is_none() {
+            errors.push("error-shooter-not-in-game");
+        }
+        if goalie.is_none() {
+            errors.push("error-goalie-not-in-game");
+        }
+        if let (Some(shooter), Some(goalie)) = (shooter, goalie) {
+            if shooter.team_id == goalie.team_id {
+                errors.push("error-goalie-same-team");
+            }
+        }
+        if let Some(blocker) = self.blocker {
+            match (find(blocker), shooter) {
+                (None, _) => errors.push("error-blocker-not-in-game"),
+                (Some(blocker), Some(shooter)) if blocker.team_id == shooter.team_id => {
+                    errors.push("error-blocker-same-team");
+                }
+                _ => {}
+            }
+            if self.goal {
+                errors.push("error-blocked-goal");
+            }
+        }
+        if self.goal && !self.on_net {
+            errors.push("error-goal-not-on-net");
+        }
+        let assistants = [self.assistant, self.assistant_second];
+        if !self.goal && assistants.iter().any(Option::is_some) {
+            errors.push("error-assist-without-goal");
+        }
+        if self.assistant.is_none() && self.assistant_second.is_some() {
+            errors.push("error-second-assist-only");
+        }
+        for assistant in assistants.into_iter().flatten() {
+            match (find(assistant), shooter) {
+                (None, _) => errors.push("error-assistant-not-in-game"),
+                (Some(assistant), Some(shooter))
+                    if assistant.team_id != shooter.team_id || assistant.id == shooter.id =>
+                {
+                    errors.push("error-assistant-wrong-team");
+                }
+                _ => {}
+            }
+        }
+        if self.assistant.is_some() && self.assistant == self.assistant_second {
+            errors.push("error-assistant-twice");
+        }
+        let shooter_skaters = self.shooter_skaters.unwrap_or(FULL_STRENGTH);
+        let opponent_skaters = self.opponent_skaters.unwrap_or(FULL_STRENGTH);
+        let skaters = MIN_SKATERS..=FULL_STRENGTH;
+        if !skaters.contains(&shooter_skaters) || !skaters.contains(&opponent_skaters) {
+            errors.push("error-invalid-skaters");
+        }
+        let period_time = validate_period_time(periods, self.period, &self.period_time, &mut errors);
+        // shootout attempts are recorded apart from shots, so they do not count in the stats
+        if periods
+            .iter()
+            .any(|period| period.id == self.period && period.kind == "SO")
+        {
+            errors.push("error-shot-in-shootout");
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(InsertShot {
+            shooter: self.shooter,
+            goalie: self.goalie,
+            assistant: self.assistant,
+            period: self.period,
+            period_time: period_time.unwrap_or_default(),
+            video_timestamp: None,
+            blocker: self.blocker,
+            on_net: self.on_net,
+            assistant_second: self.assistant_second,
+            goal: self.goal,
+            shooter_skaters,
+            opponent_skaters,
+            empty_net: self.empty_net,
+        })
+    }
+}
+
+/// The penalty lengths which can be given, in minutes:
+/// minor, double minor, major, misconduct, game misconduct and match penalties.
+pub const PENALTY_MINUTES: [i32; 6] = [2, 4, 5, 10, 20, 25];
+
+/// A penalty entered from the scorekeeping page.
+/// All player fields are `game_players` ids.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NewPenalty {
+    pub offender: i32,
+    /// Who serves the penalty, if not the offender.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub served_by: Option<i32>,
+    pub infraction: i32,
+    pub minutes: i32,
+    pub period: i32,
+    pub period_time: String,
+}
+
+impl NewPenalty {
+    /// Checks the penalty against the game's roster and periods.
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        roster: &[RosterPlayer],
+        periods: &[PeriodDetails],
+        infractions: &[Infraction],
+    ) -> Result<InsertPenalty, Vec<&'static str>> {
+        let mut errors = Vec::new();
+        let find = |id: i32| roster.iter().find(|player| player.id == id);
+        let offender = find(self.offender);
+        if offender.is_none() {
+            errors.push("error-offender-not-in-game");
+        }
+        if let Some(served_by) = self.served_by {
+            match (find(served_by), offender) {
+                (None, _) => errors.push("error-served-by-not-in-game"),
+                (Some(served_by), Some(offender)) if served_by.team_id != offender.team_id => {
+                    errors.push("error-served-by-wrong-team");
+                }
+                _ => {}
+            }
+        }
+        if !infractions.iter().any(|infraction| infraction.id == self.infraction) {
+            errors.push("error-unknown-infraction");
+        }
+        if !PENALTY_MINUTES.contains(&self.minutes) {
+            errors.push("error-invalid-minutes");
+        }
+        let period_time = validate_period_time(periods, self.period, &self.period_time, &mut errors);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(InsertPenalty {
+            offender: self.offender,
+            served_by: self.served_by.unwrap_or(self.offender),
+            infraction: self.infraction,
+            minutes: self.minutes,
+            period: self.period,
+            period_time: period_time.unwrap_or_default(),
+        })
+    }
+}
+
+/// A shootout attempt entered from the scorekeeping page.
+/// Both player fields are `game_players` ids; the attempt gets the next number of the shootout.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NewShootoutAttempt {
+    pub shooter: i32,
+    pub goalie: i32,
+    #[serde(default)]
+    pub goal: bool,
+}
+
+impl NewShootoutAttempt {
+    /// Checks the attempt against the game's roster and the attempts before it;
+    /// `tied` is whether the score is tied, since only a tied game goes to a shootout.
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        game_id: i32,
+        roster: &[RosterPlayer],
+        attempts: &[ShootoutAttemptDetails],
+        tied: bool,
+    ) -> Result<InsertShootoutAttempt, Vec<&'static str>> {
+        let mut errors = Vec::new();
+        if !tied {
+            errors.push("error-shootout-not-tied");
+        }
+        let find = |id: i32| roster.iter().find(|player| player.id == id);
+        let shooter = find(self.shooter);
+        let goalie = find(self.goalie);
+        if shooter.is_none() {
+            errors.push("error-shooter-not-in-game");
+        }
+        if goalie.is_none() {
+            errors.push("error-goalie-not-in-game");
+        }
+        if let (Some(shooter), Some(goalie)) = (shooter, goalie) {
+            if shooter.team_id == goalie.team_id {
+                errors.push("error-goalie-same-team");
+            }
+        }
+        let last = attempts.iter().max_by_key(|attempt| attempt.attempt_number);
+        if let (Some(shooter), Some(last)) = (shooter, last) {
+            if shooter.team_id == last.team_id {
+                errors.push("error-shootout-same-team-twice");
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(InsertShootoutAttempt {
+            game: game_id,
+            shooter: self.shooter,
+            goalie: self.goalie,
+            attempt_number: last.map_or(1, |last| last.attempt_number + 1),
+            goal: self.goal,
+        })
+    }
+}
+
+/// The highest number a player can wear.
+pub const MAX_PLAYER_NUMBER: i32 = 99;
+
+/// A player added to a team's lineup from the lineup page.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NewGamePlayer {
+    pub player: i32,
+    pub player_number: i32,
+    pub position: i32,
+}
+
+impl NewGamePlayer {
+    /// Checks the player against the game's roster, both teams included; numbers only need to be unique
+    /// among the players of `team_id`, and coaches do not need unique numbers.
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        game_id: i32,
+        team_id: i32,
+        roster: &[RosterPlayer],
+        players: &[Player],
+        positions: &[Position],
+    ) -> Result<InsertGamePlayer, Vec<&'static str>> {
+        let mut errors = Vec::new();
+        if !players.iter().any(|player| player.id == self.player) {
+            errors.push("error-unknown-player");
+        }
+        if roster.iter().any(|dressed| dressed.player_id == self.player) {
+            errors.push("error-player-already-dressed");
+        }
+        if !(0..=MAX_PLAYER_NUMBER).contains(&self.player_number) {
+            errors.push("error-invalid-player-number");
+        }
+        match positions.iter().find(|position| position.id == self.position) {
+            None => errors.push("error-unknown-position"),
+            Some(position) if !position.is_staff() => {
+                let taken = roster.iter().any(|dressed| {
+                    dressed.team_id == team_id
+                        && dressed.player_number == self.player_number
+                        && !STAFF_POSITIONS.contains(&dressed.position_short_name.as_str())
+                });
+                if taken {
+                    errors.push("error-player-number-taken");
+                }
+            }
+            Some(_) => {}
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(InsertGamePlayer {
+            team: team_id,
+            player: self.player,
+            position: self.position,
+            game: game_id,
+            player_number: self.player_number,
+        })
+    }
+}
+
+/// The translation key for a lineup entry which the database turned away after [`NewGamePlayer::validate`]
+/// passed, because someone else dressed the player or took the number in the meantime.
+pub fn lineup_conflict(e: &sqlx::Error) -> Option<&'static str> {
+    let sqlx::Error::Database(e) = e else {
+        return None;
+    };
+    match e.constraint() {
+        Some("player_number_is_free") => Some("error-player-number-taken"),
+        Some("one_lineup_entry_per_player") => Some("error-player-already-dressed"),
+        _ => None,
+    }
+}
+
+/// A player taken off a team's lineup from the lineup page.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RemoveGamePlayer {
+    /// The `game_players` id.
+    pub game_player: i32,
+}
+
+impl RemoveGamePlayer {
+    /// Checks that the player is in `team_id`'s lineup and that nothing was recorded for them yet,
+    /// as shots and penalties refer to the lineup; `has_events` comes from [`crate::model::GamePlayer::has_events`].
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        team_id: i32,
+        roster: &[RosterPlayer],
+        has_events: bool,
+    ) -> Result<i32, Vec<&'static str>> {
+        let in_lineup = roster
+            .iter()
+            .any(|dressed| dressed.id == self.game_player && dressed.team_id == team_id);
+        if !in_lineup {
+            return Err(vec!["error-player-not-in-lineup"]);
+        }
+        if has_events {
+            return Err(vec!["error-player-has-events"]);
+        }
+        Ok(self.game_player)
+    }
+}
+
+/// The format of a `datetime-local` input, which some browsers send with seconds.
+const DATE_TIME_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// Parses the value of a `datetime-local` input as a time in UTC.
+pub fn parse_date_time(date_time: &str) -> Option<DateTime<Utc>> {
+    DATE_TIME_FORMATS.iter().find_map(|format| {
+        NaiveDateTime::parse_from_str(date_time.trim(), format)
+            .ok()
+            .map(|naive| Utc.from_utc_datetime(&naive))
+    })
+}
+
+/// Checks a game added or changed from the admin pages or the API.
+/// `teams` are the teams of the game's division, and `dressed_teams` the teams with a lineup for the game
+/// (see [`Game::dressed_teams`]); a game cannot be taken away from a team which is already dressed for it.
+/// Returns the translation keys of every problem found.
+pub fn validate_game(game: &InsertGame, teams: &[Team], dressed_teams: &[i32]) -> Vec<&'static str> {
+    let mut errors = Vec::new();
+    if SupportedLanguage::iter().any(|lang| {
+        game.names
+            .get(&lang)
+            .map_or(true, |name| name.trim().is_empty())
+    }) {
+        errors.push("error-missing-game-name");
+    }
+    if game.end_at <= game.start_at {
+        errors.push("error-game-ends-before-start");
+    }
+    if game.team_home == game.team_away {
+        errors.push("error-same-home-and-away");
+    }
+    let in_division = |team_id: i32| {
+        teams
+            .iter()
+            .any(|team| team.id == team_id && team.division == game.division)
+    };
+    if !in_division(game.team_home) || !in_division(game.team_away) {
+        errors.push("error-team-not-in-division");
+    }
+    if dressed_teams
+        .iter()
+        .any(|&team| team != game.team_home && team != game.team_away)
+    {
+        errors.push("error-dressed-team-removed");
+    }
+    errors
+}
+
+/// A game added or edited from the admin pages.
+/// The times come from `datetime-local` inputs, and are in UTC.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct GameSchedule {
+    pub division: i32,
+    pub team_home: i32,
+    pub team_away: i32,
+    pub start_at: String,
+    pub end_at: String,
+    pub name_en_ca: String,
+    pub name_fr_ca: String,
+}
+
+impl GameSchedule {
+    /// Fills the form with a game as it is, to edit it.
+    pub fn from_game(game: &Game, names: &HashMap<SupportedLanguage, String>) -> Self {
+        let name = |lang| names.get(&lang).cloned().unwrap_or_default();
+        GameSchedule {
+            division: game.division,
+            team_home: game.team_home,
+            team_away: game.team_away,
+            start_at: game.start_at.format(DATE_TIME_FORMATS[0]).to_string(),
+            end_at: game.end_at.format(DATE_TIME_FORMATS[0]).to_string(),
+            name_en_ca: name(SupportedLanguage::English),
+            name_fr_ca: name(SupportedLanguage::French),
+        }
+    }
+    /// The name of the game in `lang`, as entered.
+    pub fn name(&self, lang: SupportedLanguage) -> &str {
+        match lang {
+            SupportedLanguage::English => &self.name_en_ca,
+            SupportedLanguage::French => &self.name_fr_ca,
+        }
+    }
+    /// The form field of the name in `lang`.
+    pub fn name_field(lang: SupportedLanguage) -> &'static str {
+        match lang {
+            SupportedLanguage::English => "name_en_ca",
+            SupportedLanguage::French => "name_fr_ca",
+        }
+    }
+    /// Each supported language, with the field and the value of the name in that language.
+    pub fn name_fields(&self) -> Vec<(SupportedLanguage, &'static str, &str)> {
+        SupportedLanguage::iter()
+            .map(|lang| (lang, Self::name_field(lang), self.name(lang)))
+            .collect()
+    }
+    /// Parses the times, then checks the game with [`validate_game`].
+    /// On failure, returns the translation keys of every problem found.
+    pub fn validate(
+        &self,
+        teams: &[Team],
+        dressed_teams: &[i32],
+    ) -> Result<InsertGame, Vec<&'static str>> {
+        let (Some(start_at), Some(end_at)) = (
+            parse_date_time(&self.start_at),
+            parse_date_time(&self.end_at),
+        ) else {
+            return Err(vec!["error-invalid-date-time"]);
+        };
+        let game = InsertGame {
+            division: self.division,
+            team_home: self.team_home,
+            team_away: self.team_away,
+            start_at,
+            end_at,
+            names: SupportedLanguage::iter()
+                .map(|lang| (lang, self.name(lang).trim().to_string()))
+                .collect(),
+        };
+        let errors = validate_game(&game, teams, dressed_teams);
+        if errors.is_empty() {
+            Ok(game)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::forms::{
+        parse_time, GameSchedule, NewGamePlayer, NewPenalty, NewShootoutAttempt, NewShot,
+        RemoveGamePlayer, FULL_STRENGTH,
+    };
+    use crate::languages::SupportedLanguage;
+    use crate::model::{Game, Infraction, Player, Position, Team};
+    use crate::views::{PeriodDetails, RosterPlayer, ShootoutAttemptDetails};
+    use chrono::{TimeZone, Utc};
+
+    fn roster_player(id: i32, team_id: i32) -> RosterPlayer {
+        RosterPlayer {
+            id,
+            player_id: id,
+            player_number: id,
+            first_names: "First".to_string(),
+            last_name: "Last".to_string(),
+            team_id,
+            team_name: format!("Team {team_id}"),
+            position_short_name: "C".to_string(),
+            classification: Some("B1".to_string()),
+        }
+    }
+
+    fn roster() -> Vec<RosterPlayer> {
+        vec![
+            roster_player(1, 1),
+            roster_player(2, 1),
+            roster_player(3, 1),
+            roster_player(4, 2),
+            roster_player(5, 2),
+        ]
+    }
+
+    fn periods() -> Vec<PeriodDetails> {
+        vec![
+            PeriodDetails {
+                id: 10,
+                period_type: 1,
+                period_length: 1200,
+                short_name: "1".to_string(),
+                kind: "REG".to_string(),
+            },
+            PeriodDetails {
+                id: 12,
+                period_type: 5,
+                period_length: 0,
+                short_name: "SO".to_string(),
+                kind: "SO".to_string(),
+            },
+        ]
+    }
+
+    fn goal() -> NewShot {
+        NewShot {
+            shooter: 1,
+            goalie: 5,
+            on_net: true,
+            goal: true,
+            assistant: Some(2),
+            assistant_second: Some(3),
+            period: 10,
+            period_time: "12:34".to_string(),
+            ..NewShot::default()
+        }
+    }
+
+    #[test]
+    fn check_parse_time() {
+        assert_eq!(parse_time("12:34"), Some(754));
+        assert_eq!(parse_time("0:05"), Some(5));
+        assert_eq!(parse_time("1:60"), None);
+        assert_eq!(parse_time("1234"), None);
+    }
+
+    #[test]
+    fn valid_goal() {
+        let shot = goal().validate(&roster(), &periods()).unwrap();
+        assert_eq!(shot.period_time, 754);
+        assert_eq!(shot.assistant, Some(2));
+        assert!(shot.goal);
+        assert_eq!(shot.shooter_skaters, FULL_STRENGTH, "Teams are at full strength by default.");
+        let mut power_play = goal();
+        power_play.opponent_skaters = Some(4);
+        let shot = power_play.validate(&roster(), &periods()).unwrap();
+        assert_eq!((shot.shooter_skaters, shot.opponent_skaters), (5, 4));
+    }
+
+    #[test]
+    fn invalid_goals() {
+        let mut shot = goal();
+        shot.goalie = 2;
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-goalie-same-team"]
+        );
+        let mut shot = goal();
+        shot.assistant = Some(4);
+        shot.assistant_second = None;
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-assistant-wrong-team"]
+        );
+        let mut shot = goal();
+        shot.goal = false;
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-assist-without-goal"]
+        );
+        let mut shot = goal();
+        shot.period = 11;
+        shot.shooter = 99;
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-shooter-not-in-game", "error-period-not-in-game"]
+        );
+        let mut shot = goal();
+        shot.period_time = "20:01".to_string();
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-time-past-period"]
+        );
+        let mut shot = goal();
+        shot.shooter_skaters = Some(6);
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-invalid-skaters"]
+        );
+        let mut shot = goal();
+        shot.period = 12;
+        shot.period_time = "00:00".to_string();
+        assert_eq!(
+            shot.validate(&roster(), &periods()).unwrap_err(),
+            vec!["error-shot-in-shootout"]
+        );
+    }
+
+    fn infractions() -> Vec<Infraction> {
+        vec![Infraction {
+            id: 1,
+            short_name: "TRIP".to_string(),
+            name: Some("tripping".to_string()),
+        }]
+    }
+
+    fn penalty() -> NewPenalty {
+        NewPenalty {
+            offender: 1,
+            served_by: None,
+            infraction: 1,
+            minutes: 2,
+            period: 10,
+            period_time: "05:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn valid_penalty() {
+        let penalty = penalty().validate(&roster(), &periods(), &infractions()).unwrap();
+        assert_eq!(penalty.served_by, 1, "The offender serves the penalty by default.");
+        assert_eq!(penalty.period_time, 300);
+        let mut served = self::penalty();
+        served.served_by = Some(2);
+        let served = served.validate(&roster(), &periods(), &infractions()).unwrap();
+        assert_eq!(served.served_by, 2);
+    }
+
+    #[test]
+    fn invalid_penalties() {
+        let mut penalty = penalty();
+        penalty.served_by = Some(4);
+        penalty.minutes = 3;
+        assert_eq!(
+            penalty.validate(&roster(), &periods(), &infractions()).unwrap_err(),
+            vec!["error-served-by-wrong-team", "error-invalid-minutes"]
+        );
+        let mut penalty = self::penalty();
+        penalty.offender = 99;
+        penalty.infraction = 2;
+        penalty.period_time = "25:00".to_string();
+        assert_eq!(
+            penalty.validate(&roster(), &periods(), &infractions()).unwrap_err(),
+            vec![
+                "error-offender-not-in-game",
+                "error-unknown-infraction",
+                "error-time-past-period"
+            ]
+        );
+    }
+
+    fn shootout_attempt(attempt_number: i32, shooter: &RosterPlayer) -> ShootoutAttemptDetails {
+        ShootoutAttemptDetails {
+            id: attempt_number,
+            attempt_number,
+            goal: false,
+            player_id: shooter.player_id,
+            player_first_names: shooter.first_names.clone(),
+            player_last_name: shooter.last_name.clone(),
+            player_number: shooter.player_number,
+            team_id: shooter.team_id,
+            team_name: shooter.team_name.clone(),
+            goalie_first_names: "First".to_string(),
+            goalie_last_name: "Last".to_string(),
+            goalie_number: 1,
+        }
+    }
+
+    #[test]
+    fn check_shootout_attempts() {
+        let roster = roster();
+        let attempt = NewShootoutAttempt {
+            shooter: 1,
+            goalie: 5,
+            goal: true,
+        };
+        let first = attempt.validate(7, &roster, &[], true).unwrap();
+        assert_eq!((first.game, first.attempt_number), (7, 1));
+        let away = NewShootoutAttempt {
+            shooter: 4,
+            goalie: 3,
+            goal: false,
+        };
+        let second = away
+            .validate(7, &roster, &[shootout_attempt(1, &roster[0])], true)
+            .unwrap();
+        assert_eq!(second.attempt_number, 2);
+        assert_eq!(
+            attempt
+                .validate(7, &roster, &[shootout_attempt(1, &roster[1])], true)
+                .unwrap_err(),
+            vec!["error-shootout-same-team-twice"],
+            "The teams take turns."
+        );
+        assert_eq!(
+            attempt.validate(7, &roster, &[], false).unwrap_err(),
+            vec!["error-shootout-not-tied"]
+        );
+        let same_team = NewShootoutAttempt {
+            shooter: 1,
+            goalie: 2,
+            goal: false,
+        };
+        assert_eq!(
+            same_team.validate(7, &roster, &[], true).unwrap_err(),
+            vec!["error-goalie-same-team"]
+        );
+    }
+
+    fn players() -> Vec<Player> {
+        (1..=7)
+            .map(|id| Player {
+                id,
+                first_names: "First".to_string(),
+                last_name: "Last".to_string(),
+                weight_kg: None,
+                height_cm: None,
+            })
+            .collect()
+    }
+
+    fn positions() -> Vec<Position> {
+        vec![
+            Position {
+                id: 1,
+                name: "Center".to_string(),
+                short_name: "C".to_string(),
+            },
+            Position {
+                id: 7,
+                name: "Assistant Coach".to_string(),
+                short_name: "AC".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn valid_lineup_entry() {
+        let roster = roster();
+        let entry = NewGamePlayer {
+            player: 6,
+            player_number: 4,
+            position: 1,
+        };
+        let inserted = entry.validate(7, 1, &roster, &players(), &positions()).unwrap();
+        assert_eq!(
+            (inserted.game, inserted.team, inserted.player, inserted.player_number),
+            (7, 1, 6, 4),
+            "Number 4 is only taken on the other team."
+        );
+        let coach = NewGamePlayer {
+            player: 6,
+            player_number: 2,
+            position: 7,
+        };
+        assert!(
+            coach.validate(7, 1, &roster, &players(), &positions()).is_ok(),
+            "Coaches do not need a unique number."
+        );
+    }
+
+    #[test]
+    fn invalid_lineup_entry() {
+        let roster = roster();
+        let taken = NewGamePlayer {
+            player: 6,
+            player_number: 2,
+            position: 1,
+        };
+        assert_eq!(
+            taken.validate(7, 1, &roster, &players(), &positions()).unwrap_err(),
+            vec!["error-player-number-taken"]
+        );
+        let dressed = NewGamePlayer {
+            player: 4,
+            player_number: 20,
+            position: 1,
+        };
+        assert_eq!(
+            dressed.validate(7, 1, &roster, &players(), &positions()).unwrap_err(),
+            vec!["error-player-already-dressed"],
+            "A player can not be dressed for both teams."
+        );
+        let unknown = NewGamePlayer {
+            player: 8,
+            player_number: 100,
+            position: 3,
+        };
+        assert_eq!(
+            unknown.validate(7, 1, &roster, &players(), &positions()).unwrap_err(),
+            vec![
+                "error-unknown-player",
+                "error-invalid-player-number",
+                "error-unknown-position"
+            ]
+        );
+    }
+
+    #[test]
+    fn lineup_removal() {
+        let roster = roster();
+        let removal = RemoveGamePlayer { game_player: 2 };
+        assert_eq!(removal.validate(1, &roster, false), Ok(2));
+        assert_eq!(
+            removal.validate(2, &roster, false).unwrap_err(),
+            vec!["error-player-not-in-lineup"]
+        );
+        assert_eq!(
+            removal.validate(1, &roster, true).unwrap_err(),
+            vec!["error-player-has-events"]
+        );
+    }
+
+    fn division_teams() -> Vec<Team> {
+        [(1, 1), (2, 1), (3, 2), (4, 1)]
+            .into_iter()
+            .map(|(id, division)| Team {
+                id,
+                division,
+                image: None,
+                name: None,
+            })
+            .collect()
+    }
+
+    fn schedule() -> GameSchedule {
+        GameSchedule {
+            division: 1,
+            team_home: 1,
+            team_away: 2,
+            start_at: "2023-09-01T18:00".to_string(),
+            end_at: "2023-09-01T19:30".to_string(),
+            name_en_ca: "Game 5".to_string(),
+            name_fr_ca: " Match 5 ".to_string(),
+        }
+    }
+
+    #[test]
+    fn valid_game() {
+        let game = schedule().validate(&division_teams(), &[]).unwrap();
+        assert_eq!(game.start_at, Utc.with_ymd_and_hms(2023, 9, 1, 18, 0, 0).unwrap());
+        assert_eq!(game.end_at, Utc.with_ymd_and_hms(2023, 9, 1, 19, 30, 0).unwrap());
+        assert_eq!(game.names[&SupportedLanguage::English], "Game 5");
+        assert_eq!(game.names[&SupportedLanguage::French], "Match 5");
+        let with_seconds = GameSchedule {
+            start_at: "2023-09-01T18:00:30".to_string(),
+            ..schedule()
+        };
+        assert!(
+            with_seconds.validate(&division_teams(), &[1, 2]).is_ok(),
+            "Browsers may send the seconds."
+        );
+        let existing = Game {
+            id: 5,
+            division: game.division,
+            team_home: game.team_home,
+            team_away: game.team_away,
+            name: None,
+            start_at: game.start_at,
+            end_at: game.end_at,
+        };
+        let edited = GameSchedule::from_game(&existing, &game.names);
+        assert_eq!(edited.start_at, "2023-09-01T18:00");
+        assert_eq!(edited.validate(&division_teams(), &[]), Ok(game));
+    }
+
+    #[test]
+    fn invalid_game() {
+        let teams = division_teams();
+        let same_teams = GameSchedule {
+            team_away: 1,
+            ..schedule()
+        };
+        assert_eq!(
+            same_teams.validate(&teams, &[]).unwrap_err(),
+            vec!["error-same-home-and-away"]
+        );
+        let other_division = GameSchedule {
+            team_away: 3,
+            ..schedule()
+        };
+        assert_eq!(
+            other_division.validate(&teams, &[]).unwrap_err(),
+            vec!["error-team-not-in-division"]
+        );
+        let unnamed_and_backwards = GameSchedule {
+            name_fr_ca: "  ".to_string(),
+            end_at: "2023-09-01T17:00".to_string(),
+            ..schedule()
+        };
+        assert_eq!(
+            unnamed_and_backwards.validate(&teams, &[]).unwrap_err(),
+            vec!["error-missing-game-name", "error-game-ends-before-start"]
+        );
+        let bad_time = GameSchedule {
+            start_at: "Friday at six".to_string(),
+            ..schedule()
+        };
+        assert_eq!(
+            bad_time.validate(&teams, &[]).unwrap_err(),
+            vec!["error-invalid-date-time"]
+        );
+        let new_opponent = GameSchedule {
+            team_away: 4,
+            ..schedule()
+        };
+        assert!(new_opponent.validate(&teams, &[1]).is_ok());
+        assert_eq!(
+            new_opponent.validate(&teams, &[1, 2]).unwrap_err(),
+            vec!["error-dressed-team-removed"],
+            "The lineup of the away team would be left behind."
+        );
+    }
+}
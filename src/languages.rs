@@ -23,6 +23,7 @@ use derive_more::Display;
     AsRefStr,
     PartialEq,
     Eq,
+    utoipa::ToSchema,
 )]
 #[repr(i32)]
 pub enum SupportedLanguage {
@@ -75,6 +76,11 @@ impl SupportedLanguage {
         }
         .to_string()
     }
+    /// Finds the language from the first segment of a URL path, like the `en-ca` in `/en-ca/login/`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let segment = path.trim_start_matches('/').split('/').next()?;
+        Self::iter().find(|lang| lang.to_string() == segment)
+    }
     pub fn id(self) -> i32 {
         match self {
             Self::English => 1,
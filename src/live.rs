@@ -0,0 +1,136 @@
+use crate::languages::SupportedLanguage;
+use crate::views::{game_score, shot_details, ShotDetails, TeamStats};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::broadcast;
+
+/// The Postgres channel which the `shot_inserted` trigger notifies.
+pub const SHOTS_CHANNEL: &str = "shots";
+
+/// The payload sent by the `shot_inserted` trigger.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct ShotInserted {
+    pub game: i32,
+    pub shot: i32,
+}
+
+/// What is pushed to spectators of a game whenever a shot is recorded.
+#[derive(Serialize, Debug)]
+pub struct GameUpdate {
+    pub shot: ShotDetails,
+    pub teams: Vec<TeamStats>,
+}
+
+/// Listens for shots being inserted (by any connection to the database), and sends them to all subscribers.
+/// This only returns if the listener can not be set up; lost connections are retried by `PgListener`.
+pub async fn listen(
+    pool: PgPool,
+    sender: broadcast::Sender<ShotInserted>,
+) -> Result<(), sqlx::Error> {
+    forward(subscribe(&pool).await?, sender).await
+}
+
+/// Connects a listener to the channel of the `shot_inserted` trigger; every shot inserted after this
+/// returns is received by it.
+pub async fn subscribe(pool: &PgPool) -> Result<PgListener, sqlx::Error> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(SHOTS_CHANNEL).await?;
+    Ok(listener)
+}
+
+/// Sends the shots received by a [`subscribe`]d listener to all subscribers.
+pub async fn forward(
+    mut listener: PgListener,
+    sender: broadcast::Sender<ShotInserted>,
+) -> Result<(), sqlx::Error> {
+    loop {
+        let notification = listener.recv().await?;
+        match serde_json::from_str::<ShotInserted>(notification.payload()) {
+            // an error only means nobody is watching a game right now
+            Ok(inserted) => drop(sender.send(inserted)),
+            Err(e) => log::warn!("Invalid payload on the {SHOTS_CHANNEL} channel: {e}"),
+        }
+    }
+}
+
+pub async fn game_update(
+    pool: &PgPool,
+    inserted: ShotInserted,
+    lang: SupportedLanguage,
+) -> Result<GameUpdate, sqlx::Error> {
+    Ok(GameUpdate {
+        shot: shot_details(pool, inserted.shot, lang.into()).await?,
+        teams: game_score(pool, inserted.game, lang.into()).await?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::live::{forward, game_update, subscribe};
+    use crate::languages::SupportedLanguage;
+    use crate::model::InsertShot;
+    use ormx::Insert;
+    use std::env;
+    use std::time::Duration;
+    use tokio::sync::broadcast;
+
+    #[test]
+    fn check_shot_insert_is_broadcast() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let (sender, mut receiver) = broadcast::channel(8);
+            let listener = subscribe(&pool).await.unwrap();
+            tokio::spawn(forward(listener, sender));
+            let mut conn = pool.acquire().await.unwrap();
+            let shot = InsertShot {
+                shooter: 2,
+                goalie: 12,
+                assistant: None,
+                period: 1,
+                period_time: 1,
+                video_timestamp: None,
+                blocker: None,
+                on_net: true,
+                assistant_second: None,
+                goal: false,
+                shooter_skaters: 5,
+                opponent_skaters: 5,
+                empty_net: false,
+            }
+            .insert(&mut conn)
+            .await
+            .unwrap();
+            let inserted = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await;
+            let update = match &inserted {
+                Ok(Ok(inserted)) => Some(game_update(&pool, *inserted, SupportedLanguage::English).await),
+                _ => None,
+            };
+            // the shot is committed, so it is removed before anything is asserted, to leave the fixtures as they were
+            sqlx::query("DELETE FROM shots WHERE id=$1;")
+                .bind(shot.id)
+                .execute(&pool)
+                .await
+                .unwrap();
+            let inserted = inserted
+                .expect("No notification was received for the new shot.")
+                .unwrap();
+            assert_eq!(inserted.shot, shot.id);
+            assert_eq!(inserted.game, 1);
+            let update = update.unwrap().unwrap();
+            assert!(!update.shot.is_goal);
+            assert_eq!(update.teams.len(), 2);
+        })
+    }
+
+    /// A simple function to connect to the database.
+    async fn db_connect() -> sqlx::PgPool {
+        let db_url = env::var("DATABASE_URL")
+            .expect("DATABASE_URL environment variable must be set to run tests.");
+        sqlx::postgres::PgPoolOptions::new()
+            .max_connections(3)
+            .connect(&db_url)
+            .await
+            .expect("Active database connection must be made")
+    }
+}
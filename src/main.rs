@@ -1,10 +1,19 @@
 #![warn(clippy::all, clippy::pedantic, unsafe_code)]
 
+mod api;
+mod auth;
+mod classification;
+mod cli;
+mod config;
 mod db;
+mod errors;
 mod forms;
 mod filters;
 mod languages;
+mod live;
 mod model;
+mod notifications;
+mod standings;
 mod traits;
 mod views;
 
@@ -67,7 +76,7 @@ macro_rules! impl_url_gen {
       use crate::$struct;
       #[test]
       fn test_lang_link_types() {
-        println!("{:?}", $struct::lang_link(SupportedLanguage::English, 0));
+        println!("{:?}", $struct::lang_link(SupportedLanguage::English, $(<$ty>::default()),*));
       }
     }
   }
@@ -78,26 +87,42 @@ use static_assertions::assert_impl_all;
 use traits::TemplateUrl;
 #[macro_use]
 extern crate ibihf_macros;
-use askama::i18n::{langid, Locale};
+use askama::i18n::Locale;
 askama::i18n::load!(LOCALES);
 
-use crate::model::{Division, Game, Language, League, Player};
+use crate::errors::{AppError, OrAppError, OrNotFound};
+use crate::classification::LineupProblem;
+use crate::model::{
+    ClassificationRule, Division, Game, GamePlayer, Infraction, Language, League, Player,
+    PlayerClassification, PointsSystem, Position, Role, Season, Team, User,
+};
 use languages::{LangLink, SupportedLanguage};
-use views::{GoalDetails, IihfStatsI64, PlayerStats, ShotDetails, TeamStats};
+use standings::Standing;
+use views::{
+    GoalDetails, GoalieStats, PenaltyDetails, PeriodDetails, PlayerDivisionStats, PlayerGame,
+    LeaderSort, LeaderStats, PlayerStats, RosterPlayer, ShootoutAttemptDetails, ShotDetails,
+    TeamGame, TeamRosterPlayer, TeamStats,
+};
 
 use askama::Template;
 use axum::{
     Form,
-    extract::{Path, State},
-    http::StatusCode,
-    response::IntoResponse,
-    routing::get,
+    extract::{FromRef, Path, Query, State},
+    http::{StatusCode, Uri},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Redirect, Response,
+    },
+    routing::{get, post},
     Router,
 };
-use ormx::Table;
-use sqlx::{Pool, Postgres};
-use std::net::SocketAddr;
+use axum_extra::extract::cookie::{Key, SignedCookieJar};
+use clap::Parser;
+use ormx::{Delete, Insert, Table};
+use sqlx::{PgPool, Pool, Postgres};
+use std::convert::Infallible;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 const VERSION: &str = "0.5.0-beta";
 
@@ -152,11 +177,104 @@ struct DivisionListTemplate<'a> {
     lang_links: Vec<LangLink>,
     league: League,
     divisions: Vec<Division>,
+    season_select: SeasonSelectTemplate<'a>,
     lang: SupportedLanguage,
 }
 impl_url_gen!(DivisionListTemplate, id: i32);
 assert_impl_all!(DivisionListTemplate: TemplateUrl);
 
+#[derive(Template)]
+#[template(path = "partials/season_select.html")]
+struct SeasonSelectTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    seasons: Vec<Season>,
+    selected: Option<i32>,
+}
+impl SeasonSelectTemplate<'_> {
+    fn is_selected(&self, season: &Season) -> bool {
+        self.selected == Some(season.id)
+    }
+    fn selected_season(&self) -> Option<&Season> {
+        self.seasons.iter().find(|season| self.is_selected(season))
+    }
+}
+
+/// The standings of one division in a season which has ended.
+struct ArchivedStandings<'a> {
+    division_id: i32,
+    division_name: Option<String>,
+    standings: IihfTeamStatsTableTemplate<'a>,
+}
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "season_archive_url", url_key_template = "season_archive_url_tmpl")]
+#[template(path = "season_archive.html")]
+struct SeasonArchiveTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    league: League,
+    seasons: Vec<(Season, Vec<ArchivedStandings<'a>>)>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(SeasonArchiveTemplate, id: i32);
+assert_impl_all!(SeasonArchiveTemplate: TemplateUrl);
+
+#[derive(Template)]
+#[template(path = "partials/leaders_table.html")]
+struct LeadersTableTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    leaders: Vec<LeaderStats>,
+    season_select: SeasonSelectTemplate<'a>,
+    min_games: i64,
+    sort: LeaderSort,
+    lang: SupportedLanguage,
+}
+impl LeadersTableTemplate<'_> {
+    fn is_sorted_by(&self, sort: &str) -> bool {
+        self.sort.as_str() == sort
+    }
+    /// The query to rank the same leaders by another column.
+    fn sort_href(&self, sort: &str) -> String {
+        let season = self
+            .season_select
+            .selected
+            .map(|season_id| season_id.to_string())
+            .unwrap_or_default();
+        format!("?season={season}&min_games={}&sort={sort}", self.min_games)
+    }
+}
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "league_leaders_url", url_key_template = "league_leaders_url_tmpl")]
+#[template(path = "league_leaders.html")]
+struct LeagueLeadersTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    league: League,
+    leaders: LeadersTableTemplate<'a>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(LeagueLeadersTemplate, id: i32);
+assert_impl_all!(LeagueLeadersTemplate: TemplateUrl);
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "division_leaders_url", url_key_template = "division_leaders_url_tmpl")]
+#[template(path = "division_leaders.html")]
+struct DivisionLeadersTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    division: Division,
+    leaders: LeadersTableTemplate<'a>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(DivisionLeadersTemplate, id: i32);
+assert_impl_all!(DivisionLeadersTemplate: TemplateUrl);
+
 #[derive(Template, TemplateUrl)]
 #[urls(url_key = "login_url", url_key_template = "login_url_tmpl")]
 #[template(path = "form.html")]
@@ -166,10 +284,88 @@ struct LoginFormTemplate<'a> {
     lang_links: Vec<LangLink>,
     lang: SupportedLanguage,
     form: forms::Login,
+    error: Option<String>,
 }
 impl_url_gen!(LoginFormTemplate, id: i32);
 assert_impl_all!(LoginFormTemplate: TemplateUrl);
 
+#[derive(Template)]
+#[template(path = "forbidden.html")]
+struct ForbiddenTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+}
+
+#[derive(Template)]
+#[template(path = "not_found.html")]
+struct NotFoundTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+}
+
+#[derive(Template)]
+#[template(path = "server_error.html")]
+struct ServerErrorTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+}
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "admin_url", url_key_template = "admin_url_tmpl")]
+#[template(path = "admin.html")]
+struct AdminPageTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+    user: User,
+    users: Vec<(User, Vec<Role>)>,
+    games: Vec<Game>,
+}
+impl_url_gen!(AdminPageTemplate, id: i32);
+assert_impl_all!(AdminPageTemplate: TemplateUrl);
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "new_game_url", url_key_template = "new_game_url_tmpl")]
+#[template(path = "game_form.html")]
+struct NewGameTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+    /// The game being edited; always `None` when adding one.
+    game_id: Option<i32>,
+    form: forms::GameSchedule,
+    divisions: Vec<Division>,
+    teams: Vec<Team>,
+    errors: Vec<String>,
+}
+impl_url_gen!(NewGameTemplate, id: i32);
+assert_impl_all!(NewGameTemplate: TemplateUrl);
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "edit_game_url", url_key_template = "edit_game_url_tmpl")]
+#[template(path = "game_form.html")]
+struct EditGameTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    lang: SupportedLanguage,
+    game_id: Option<i32>,
+    form: forms::GameSchedule,
+    divisions: Vec<Division>,
+    teams: Vec<Team>,
+    errors: Vec<String>,
+}
+impl_url_gen!(EditGameTemplate, id: i32);
+assert_impl_all!(EditGameTemplate: TemplateUrl);
+
 #[derive(Template, TemplateUrl)]
 #[urls(url_key = "root_url", url_key_template = "root_url_tmpl")]
 #[template(path = "league_list.html")]
@@ -188,7 +384,8 @@ assert_impl_all!(LeagueListTemplate: TemplateUrl);
 struct IihfTeamStatsTableTemplate<'a> {
     #[locale]
     locale: Locale<'a>,
-    iihf_stats: Vec<IihfStatsI64>,
+    standings: Vec<Standing>,
+    lang: SupportedLanguage,
 }
 
 #[derive(Template, TemplateUrl)]
@@ -199,7 +396,12 @@ struct GameListTemplate<'a> {
     locale: Locale<'a>,
     lang_links: Vec<LangLink>,
     division: Division,
+    season_select: SeasonSelectTemplate<'a>,
+    points_system: PointsSystem,
     iihf_team_stats_table: IihfTeamStatsTableTemplate<'a>,
+    team_stats: TeamGameStatsTemplate<'a>,
+    player_stats: IndividualGamePointsTableTemplate<'a>,
+    goalie_stats: GoalieStatsTableTemplate<'a>,
     games: Vec<Game>,
     lang: SupportedLanguage,
 }
@@ -215,6 +417,30 @@ struct ShotsTableTemplate<'a> {
     lang: SupportedLanguage,
 }
 
+#[derive(Template)]
+#[template(path = "partials/goalie_stats_table.html")]
+struct GoalieStatsTableTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    goalies: Vec<GoalieStats>,
+}
+
+#[derive(Template)]
+#[template(path = "partials/penalty_summary_table.html")]
+struct PenaltySummaryTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    penalties: Vec<PenaltyDetails>,
+}
+
+#[derive(Template)]
+#[template(path = "partials/shootout_summary_table.html")]
+struct ShootoutSummaryTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    attempts: Vec<ShootoutAttemptDetails>,
+}
+
 #[derive(Template, TemplateUrl)]
 #[urls(url_key = "game_url", url_key_template = "game_url_tmpl")]
 #[template(path = "game_score_page.html")]
@@ -224,15 +450,87 @@ struct GameScorePageTemplate<'a> {
     lang_links: Vec<LangLink>,
     game: Game,
     division: Division,
+    home_team: Team,
+    away_team: Team,
     box_score: BoxScoreTemplate<'a>,
     team_stats: TeamGameStatsTemplate<'a>,
     individual_stats: IndividualGamePointsTableTemplate<'a>,
+    goalie_stats: GoalieStatsTableTemplate<'a>,
+    penalties: PenaltySummaryTemplate<'a>,
+    shootout: ShootoutSummaryTemplate<'a>,
     play_by_play: ShotsTableTemplate<'a>,
     lang: SupportedLanguage,
 }
 impl_url_gen!(GameScorePageTemplate, id: i32);
 assert_impl_all!(GameScorePageTemplate: TemplateUrl);
 
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "scorekeeper_url", url_key_template = "scorekeeper_url_tmpl")]
+#[template(path = "scorekeeper.html")]
+struct ScorekeeperPageTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    game: Game,
+    roster: Vec<RosterPlayer>,
+    periods: Vec<PeriodDetails>,
+    infractions: Vec<Infraction>,
+    penalty_minutes: &'static [i32],
+    full_strength: i32,
+    min_skaters: i32,
+    errors: Vec<String>,
+    lineup_problems: Vec<LineupProblem>,
+    home_team: Team,
+    away_team: Team,
+    penalties: PenaltySummaryTemplate<'a>,
+    shootout: ShootoutSummaryTemplate<'a>,
+    play_by_play: ShotsTableTemplate<'a>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(ScorekeeperPageTemplate, id: i32);
+assert_impl_all!(ScorekeeperPageTemplate: TemplateUrl);
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "lineup_url", url_key_template = "lineup_url_tmpl")]
+#[template(path = "lineup.html")]
+struct LineupTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    game: Game,
+    team: Team,
+    /// The players dressed for `team`.
+    lineup: Vec<RosterPlayer>,
+    /// The players who can still be dressed for the game.
+    players: Vec<Player>,
+    positions: Vec<Position>,
+    max_player_number: i32,
+    errors: Vec<String>,
+    lineup_problems: Vec<LineupProblem>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(LineupTemplate, id: i32, team: i32);
+assert_impl_all!(LineupTemplate: TemplateUrl);
+
+#[derive(Template, TemplateUrl)]
+#[urls(url_key = "team_url", url_key_template = "team_url_tmpl")]
+#[template(path = "team_page.html")]
+struct TeamPageTemplate<'a> {
+    #[locale]
+    locale: Locale<'a>,
+    lang_links: Vec<LangLink>,
+    team: Team,
+    division: Division,
+    season_select: SeasonSelectTemplate<'a>,
+    record: IihfTeamStatsTableTemplate<'a>,
+    totals: TeamGameStatsTemplate<'a>,
+    roster: Vec<TeamRosterPlayer>,
+    schedule: Vec<TeamGame>,
+    lang: SupportedLanguage,
+}
+impl_url_gen!(TeamPageTemplate, id: i32);
+assert_impl_all!(TeamPageTemplate: TemplateUrl);
+
 #[derive(Template, TemplateUrl)]
 #[urls(url_key = "player_url", url_key_template = "player_url_tmpl")]
 #[template(path = "player_page.html")]
@@ -241,43 +539,167 @@ pub struct PlayerPageTemplate<'a> {
     locale: Locale<'a>,
     lang_links: Vec<LangLink>,
     player: Player,
-    league: League,
-    league_stats: PlayerStats,
+    /// From the latest to the earliest.
+    classifications: Vec<PlayerClassification>,
+    /// The league of the player's latest game and their stats in it; `None` until they play a game.
+    latest: Option<(League, PlayerStats, Option<GoalieStats>)>,
     lifetime_stats: PlayerStats,
+    lifetime_goalie_stats: Option<GoalieStats>,
+    game_log: Vec<PlayerGame>,
+    division_stats: Vec<PlayerDivisionStats>,
     lang: SupportedLanguage,
 }
+impl_url_gen!(PlayerPageTemplate, id: i32);
 assert_impl_all!(PlayerPageTemplate: TemplateUrl);
 
 #[derive(Clone)]
 pub struct ServerState {
     db_pool: Arc<Pool<Postgres>>,
+    cookie_key: Key,
+    default_lang: SupportedLanguage,
+    shot_events: broadcast::Sender<live::ShotInserted>,
+    announcements: broadcast::Sender<notifications::GoalAnnouncement>,
+}
+impl FromRef<ServerState> for Key {
+    fn from_ref(state: &ServerState) -> Self {
+        state.cookie_key.clone()
+    }
 }
 
 #[tokio::main]
 async fn main() {
-    let pool = db::connect().await;
+    let cli = cli::Cli::parse();
+    let config = match config::Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprint!("{e}");
+            std::process::exit(1);
+        }
+    };
+    env_logger::Builder::new()
+        .filter_level(config.log_level())
+        .init();
+    let pool = match db::connect(&config).await {
+        Ok(pool) => pool,
+        Err(e) => {
+            log::error!("Unable to connect to the database: {e}");
+            std::process::exit(1);
+        }
+    };
+    let result = match cli.command.unwrap_or(cli::Command::Serve) {
+        cli::Command::Serve => {
+            serve(config, pool).await;
+            Ok(())
+        }
+        cli::Command::Migrate { action } => cli::migrate(&pool, action).await,
+        cli::Command::CreateUser {
+            user_name,
+            roles,
+            league,
+            password_stdin,
+        } => cli::create_user(&pool, user_name, roles, league, password_stdin).await,
+        cli::Command::CheckDb => cli::check_db(&pool).await,
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+async fn serve(config: config::Config, pool: PgPool) {
+    let (shot_events, _) = broadcast::channel(64);
+    tokio::spawn(live::listen(pool.clone(), shot_events.clone()));
+    let (announcements, _) = broadcast::channel(64);
+    tokio::spawn(notifications::run(
+        pool.clone(),
+        shot_events.subscribe(),
+        notifications::Sinks::from_config(&config.announcements, announcements.clone()),
+    ));
+    let cookie_key = match config.session_secret {
+        Some(ref secret) => Key::from(secret.as_bytes()),
+        None => {
+            log::warn!("No session_secret is configured, so all sessions will end when the server restarts.");
+            Key::generate()
+        }
+    };
     let state = ServerState {
         db_pool: Arc::new(pool),
+        cookie_key,
+        default_lang: config.default_locale,
+        shot_events,
+        announcements,
     };
     let router = Router::new()
         .route("/", get(language_list))
         .route("/:lang/", get(league_html))
 				.route(
 					&SupportedLanguage::English.lookup(LoginFormTemplate::URL_KEY),
-					get(login_form),
+					get(login_form).post(login),
+				)
+				.route(
+					&SupportedLanguage::English.lookup(AdminPageTemplate::URL_KEY),
+					get(admin_html),
+				)
+				.route(
+					&SupportedLanguage::English.lookup(NewGameTemplate::URL_KEY),
+					get(new_game_html).post(create_game),
+				)
+				.route(
+					&SupportedLanguage::English.lookup(EditGameTemplate::URL_KEY),
+					get(edit_game_html).post(update_game),
+				)
+				.route(
+					&SupportedLanguage::English.lookup("logout_url"),
+					post(logout),
 				)
 				.route(
 					&SupportedLanguage::English.lookup(PlayerPageTemplate::URL_KEY),
 					get(player_html),
 				)
+				.route(
+					&SupportedLanguage::French.lookup(PlayerPageTemplate::URL_KEY),
+					get(player_html),
+				)
         .route(
             &SupportedLanguage::English.lookup(DivisionListTemplate::URL_KEY),
             get(divisions_for_league_html),
         )
+        .route(
+            &SupportedLanguage::English.lookup(SeasonArchiveTemplate::URL_KEY),
+            get(season_archive_html),
+        )
+        .route(
+            &SupportedLanguage::English.lookup(LeagueLeadersTemplate::URL_KEY),
+            get(league_leaders_html),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(LeagueLeadersTemplate::URL_KEY),
+            get(league_leaders_html),
+        )
+        .route(
+            &SupportedLanguage::English.lookup(DivisionLeadersTemplate::URL_KEY),
+            get(division_leaders_html),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(DivisionLeadersTemplate::URL_KEY),
+            get(division_leaders_html),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(SeasonArchiveTemplate::URL_KEY),
+            get(season_archive_html),
+        )
         .route(
             &SupportedLanguage::English.lookup(GameListTemplate::URL_KEY),
             get(games_for_division_html),
         )
+        .route(
+            &SupportedLanguage::English.lookup(TeamPageTemplate::URL_KEY),
+            get(team_html),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(TeamPageTemplate::URL_KEY),
+            get(team_html),
+        )
         .route(
             &SupportedLanguage::English.lookup(GameScorePageTemplate::URL_KEY),
             get(score_for_game_html),
@@ -286,13 +708,82 @@ async fn main() {
             &SupportedLanguage::French.lookup(GameScorePageTemplate::URL_KEY),
             get(score_for_game_html),
         )
+        .route(
+            &SupportedLanguage::English.lookup("announcements_url"),
+            get(goal_announcement_events),
+        )
+        .route(
+            &SupportedLanguage::English.lookup("live_url"),
+            get(live_game_events),
+        )
+        .route(
+            &SupportedLanguage::French.lookup("live_url"),
+            get(live_game_events),
+        )
+        .route(
+            &SupportedLanguage::English.lookup(ScorekeeperPageTemplate::URL_KEY),
+            get(scorekeeper_html).post(record_shot),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(ScorekeeperPageTemplate::URL_KEY),
+            get(scorekeeper_html).post(record_shot),
+        )
+        .route(
+            &SupportedLanguage::English.lookup("scorekeeper_penalty_url"),
+            post(record_penalty),
+        )
+        .route(
+            &SupportedLanguage::French.lookup("scorekeeper_penalty_url"),
+            post(record_penalty),
+        )
+        .route(
+            &SupportedLanguage::English.lookup("scorekeeper_shootout_url"),
+            post(record_shootout_attempt),
+        )
+        .route(
+            &SupportedLanguage::French.lookup("scorekeeper_shootout_url"),
+            post(record_shootout_attempt),
+        )
+        .route(
+            &SupportedLanguage::English.lookup(LineupTemplate::URL_KEY),
+            get(lineup_html).post(add_to_lineup),
+        )
+        .route(
+            &SupportedLanguage::French.lookup(LineupTemplate::URL_KEY),
+            get(lineup_html).post(add_to_lineup),
+        )
+        .route(
+            &SupportedLanguage::English.lookup("lineup_remove_url"),
+            post(remove_from_lineup),
+        )
+        .route(
+            &SupportedLanguage::French.lookup("lineup_remove_url"),
+            post(remove_from_lineup),
+        )
+        .route(
+            &SupportedLanguage::English.lookup("lineup_copy_url"),
+            post(copy_previous_lineup),
+        )
+        .route(
+            &SupportedLanguage::French.lookup("lineup_copy_url"),
+            post(copy_previous_lineup),
+        )
+        .nest("/api/v1", api::router())
+        .fallback(not_found_fallback)
         .with_state(state);
-    let addr = SocketAddr::from(([127, 0, 0, 1], 8000));
-    println!("Listening on {addr}");
-    axum::Server::bind(&addr)
-        .serve(router.into_make_service())
-        .await
-        .unwrap();
+    let addr = config.listen_addr();
+    let server = match axum::Server::try_bind(&addr) {
+        Ok(server) => server,
+        Err(e) => {
+            log::error!("Unable to listen on {addr}: {e}");
+            std::process::exit(1);
+        }
+    };
+    log::info!("Listening on {addr}");
+    if let Err(e) = server.serve(router.into_make_service()).await {
+        log::error!("The server stopped: {e}");
+        std::process::exit(1);
+    }
 }
 
 async fn login_form(
@@ -303,47 +794,294 @@ async fn login_form(
     lang_links: other_lang_urls!(lang, LeagueListTemplate),
     lang,
     form: forms::Login::default(),
+    error: None,
   })
 }
 
+async fn login(
+	State(server_config): State<ServerState>,
+	Path(lang): Path<SupportedLanguage>,
+	jar: SignedCookieJar,
+	Form(form): Form<forms::Login>,
+) -> Result<Response, AppError> {
+  let user = auth::verify_login(&server_config.db_pool, &form.username, &form.password)
+    .await
+    .or_app_error(lang)?;
+  Ok(match user {
+    Some(user) => (
+      auth::start_session(jar, &user),
+      Redirect::to(&format!("/{lang}/")),
+    ).into_response(),
+    None => (StatusCode::UNAUTHORIZED, LoginFormTemplate {
+      locale: lang.into(),
+      lang_links: other_lang_urls!(lang, LeagueListTemplate),
+      lang,
+      form: forms::Login {
+        username: form.username,
+        password: String::new(),
+      },
+      error: Some(lang.lookup("login-invalid")),
+    }).into_response(),
+  })
+}
+
+async fn logout(
+	Path(lang): Path<SupportedLanguage>,
+	jar: SignedCookieJar,
+) -> impl IntoResponse {
+  (auth::end_session(jar), Redirect::to(&format!("/{lang}/")))
+}
+
+/// The page shown when a user is not allowed to see a page; see [`auth::Authorized`].
+pub fn forbidden_page(lang: SupportedLanguage) -> Response {
+  (StatusCode::FORBIDDEN, ForbiddenTemplate {
+    locale: lang.into(),
+    lang_links: other_lang_urls!(lang, LeagueListTemplate),
+    lang,
+  }).into_response()
+}
+
+/// The page shown when a page refers to something which does not exist; see [`errors::AppError`].
+pub fn not_found_page(lang: SupportedLanguage) -> Response {
+  (StatusCode::NOT_FOUND, NotFoundTemplate {
+    locale: lang.into(),
+    lang_links: other_lang_urls!(lang, LeagueListTemplate),
+    lang,
+  }).into_response()
+}
+
+async fn not_found_fallback(State(server_config): State<ServerState>, uri: Uri) -> Response {
+  not_found_page(SupportedLanguage::from_path(uri.path()).unwrap_or(server_config.default_lang))
+}
+
+/// The page shown when something went wrong on the server; see [`errors::AppError`].
+pub fn server_error_page(lang: SupportedLanguage) -> Response {
+  (StatusCode::INTERNAL_SERVER_ERROR, ServerErrorTemplate {
+    locale: lang.into(),
+    lang_links: other_lang_urls!(lang, LeagueListTemplate),
+    lang,
+  }).into_response()
+}
+
+async fn admin_html(
+	State(server_config): State<ServerState>,
+	Path(lang): Path<SupportedLanguage>,
+	admin: auth::Authorized<auth::Admin>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut users = Vec::new();
+    for user in User::all(&*server_config.db_pool).await.or_app_error(lang)? {
+        let roles = Role::by_user(&server_config.db_pool, user.id, lang.into())
+            .await
+            .or_app_error(lang)?;
+        users.push((user, roles));
+    }
+    let mut games = Game::all(&server_config.db_pool, lang.into())
+        .await
+        .or_app_error(lang)?;
+    games.sort_by(|a, b| b.start_at.cmp(&a.start_at));
+    let admin_template = AdminPageTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, AdminPageTemplate),
+        lang,
+        user: admin.user,
+        users,
+        games,
+    };
+    Ok((StatusCode::OK, admin_template))
+}
+
+async fn new_game_page<'a>(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    form: forms::GameSchedule,
+    errors: Vec<String>,
+) -> Result<NewGameTemplate<'a>, AppError> {
+    Ok(NewGameTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, NewGameTemplate),
+        lang,
+        game_id: None,
+        form,
+        divisions: Division::all(pool, lang.into()).await.or_app_error(lang)?,
+        teams: Team::all(pool, lang.into()).await.or_app_error(lang)?,
+        errors,
+    })
+}
+
+async fn edit_game_page<'a>(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    game_id: i32,
+    form: forms::GameSchedule,
+    errors: Vec<String>,
+) -> Result<EditGameTemplate<'a>, AppError> {
+    Ok(EditGameTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, EditGameTemplate, "id" => game_id),
+        lang,
+        game_id: Some(game_id),
+        form,
+        divisions: Division::all(pool, lang.into()).await.or_app_error(lang)?,
+        teams: Team::all(pool, lang.into()).await.or_app_error(lang)?,
+        errors,
+    })
+}
+
+async fn new_game_html(
+    State(server_config): State<ServerState>,
+    Path(lang): Path<SupportedLanguage>,
+    _admin: auth::Authorized<auth::Admin>,
+) -> Result<impl IntoResponse, AppError> {
+    let new_game_template = new_game_page(
+        &server_config.db_pool,
+        lang,
+        forms::GameSchedule::default(),
+        Vec::new(),
+    )
+    .await?;
+    Ok((StatusCode::OK, new_game_template))
+}
+
+async fn create_game(
+    State(server_config): State<ServerState>,
+    Path(lang): Path<SupportedLanguage>,
+    _admin: auth::Authorized<auth::Admin>,
+    Form(form): Form<forms::GameSchedule>,
+) -> Result<Response, AppError> {
+    let teams = Team::by_division(&server_config.db_pool, form.division, lang.into())
+        .await
+        .or_app_error(lang)?;
+    match form.validate(&teams, &[]) {
+        Ok(new_game) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            let game_id = Game::insert(&mut conn, &new_game).await.or_app_error(lang)?;
+            Ok(Redirect::to(&GameScorePageTemplate::lang_link(lang, game_id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let new_game_template = new_game_page(&server_config.db_pool, lang, form, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, new_game_template).into_response())
+        }
+    }
+}
+
+async fn edit_game_html(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    _admin: auth::Authorized<auth::Admin>,
+) -> Result<impl IntoResponse, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let names = Game::names(&server_config.db_pool, game.id)
+        .await
+        .or_app_error(lang)?;
+    let form = forms::GameSchedule::from_game(&game, &names);
+    let edit_game_template =
+        edit_game_page(&server_config.db_pool, lang, game.id, form, Vec::new()).await?;
+    Ok((StatusCode::OK, edit_game_template))
+}
+
+async fn update_game(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    _admin: auth::Authorized<auth::Admin>,
+    Form(form): Form<forms::GameSchedule>,
+) -> Result<Response, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let teams = Team::by_division(&server_config.db_pool, form.division, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let dressed_teams = Game::dressed_teams(&server_config.db_pool, game.id)
+        .await
+        .or_app_error(lang)?;
+    match form.validate(&teams, &dressed_teams) {
+        Ok(changed_game) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            Game::update(&mut conn, game.id, &changed_game)
+                .await
+                .or_app_error(lang)?;
+            Ok(Redirect::to(&GameScorePageTemplate::lang_link(lang, game.id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let edit_game_template =
+                edit_game_page(&server_config.db_pool, lang, game.id, form, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, edit_game_template).into_response())
+        }
+    }
+}
+
 async fn player_html(
 	State(server_config): State<ServerState>,
 	Path((lang,id)): Path<(SupportedLanguage, i32)>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, AppError> {
     let player = Player::get(&*server_config.db_pool, id)
         .await
-        .unwrap();
-		let league = Player::latest_league(&*server_config.db_pool, player.id, lang.into())
-			.await
-			.unwrap()
-			.unwrap();
-		let league_stats = League::player_stats(&*server_config.db_pool, player.id, league.id)
-			.await
-			.unwrap();
-		let lifetime_stats = Player::lifetime_stats(&*server_config.db_pool, player.id)
-			.await
-			.unwrap();
+        .or_app_error(lang)?;
+    let classifications = PlayerClassification::history(&server_config.db_pool, player.id)
+        .await
+        .or_app_error(lang)?;
+    let league = Player::latest_league(&*server_config.db_pool, player.id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let latest = match league {
+        Some(league) => {
+            let league_stats =
+                League::player_stats(&*server_config.db_pool, player.id, league.id, None)
+                    .await
+                    .or_app_error(lang)?;
+            let league_goalie_stats =
+                League::player_goalie_stats(&server_config.db_pool, player.id, league.id, None)
+                    .await
+                    .or_app_error(lang)?;
+            Some((league, league_stats, league_goalie_stats))
+        }
+        None => None,
+    };
+    let lifetime_stats = Player::lifetime_stats(&*server_config.db_pool, player.id)
+        .await
+        .or_app_error(lang)?;
+    let game_log = Player::game_log(&*server_config.db_pool, player.id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let division_stats = Player::stats_by_division(&*server_config.db_pool, player.id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let lifetime_goalie_stats = Player::lifetime_goalie_stats(&server_config.db_pool, player.id)
+        .await
+        .or_app_error(lang)?;
     let player_template = PlayerPageTemplate {
         locale: lang.into(),
-        lang_links: other_lang_urls!(lang, LeagueListTemplate),
+        lang_links: other_lang_urls!(lang, PlayerPageTemplate, "id" => id),
         lang,
-				player,
-				league,
-				league_stats,
-				lifetime_stats,
+        player,
+        classifications,
+        latest,
+        lifetime_stats,
+        lifetime_goalie_stats,
+        game_log,
+        division_stats,
     };
-    (StatusCode::OK, player_template)
+    Ok((StatusCode::OK, player_template))
 }
 
-async fn language_list(State(server_config): State<ServerState>) -> impl IntoResponse {
-    let languages = Language::all(&*server_config.db_pool).await.unwrap();
+async fn language_list(State(server_config): State<ServerState>) -> Result<impl IntoResponse, AppError> {
+    let lang = server_config.default_lang;
+    let languages = Language::all(&*server_config.db_pool)
+        .await
+        .or_app_error(lang)?;
     let lang_list_tmpl = LanguageListTemplate {
-        loc: Locale::new(langid!("en-ca"), &LOCALES),
+        loc: lang.into(),
         lang_links: Vec::new(),
         languages,
-        lang: SupportedLanguage::English,
+        lang,
     };
-    (StatusCode::OK, lang_list_tmpl)
+    Ok((StatusCode::OK, lang_list_tmpl))
 }
 
 /*
@@ -375,94 +1113,354 @@ macro_rules! get_by_id {
 async fn league_html(
     State(server_config): State<ServerState>,
     Path(lang): Path<SupportedLanguage>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, AppError> {
     let leagues = League::all(&server_config.db_pool, lang.into())
         .await
-        .unwrap();
+        .or_app_error(lang)?;
     let leagues_template = LeagueListTemplate {
         lang_links: other_lang_urls!(lang, LeagueListTemplate),
         locale: lang.into(),
         leagues,
         lang,
     };
-    (StatusCode::OK, leagues_template)
+    Ok((StatusCode::OK, leagues_template))
+}
+
+/// The seasons of a league, with the one chosen by `?season=`, or the current season if none was chosen.
+/// A season which is not part of the league is not found.
+async fn select_season<'a>(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    league_id: i32,
+    select: forms::SeasonSelect,
+) -> Result<SeasonSelectTemplate<'a>, AppError> {
+    let seasons = Season::for_league(pool, league_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let selected = match select.season {
+        Some(season_id) => Some(
+            seasons
+                .iter()
+                .find(|season| season.id == season_id)
+                .or_not_found(lang)?
+                .id,
+        ),
+        None => Season::current(&seasons, chrono::Utc::now().date_naive()).map(|season| season.id),
+    };
+    Ok(SeasonSelectTemplate {
+        locale: lang.into(),
+        seasons,
+        selected,
+    })
 }
 
 async fn divisions_for_league_html(
     State(server_config): State<ServerState>,
     Path((lang, league_id)): Path<(SupportedLanguage, i32)>,
-) -> impl IntoResponse {
+    Query(select): Query<forms::SeasonSelect>,
+) -> Result<impl IntoResponse, AppError> {
     let league = League::get(&server_config.db_pool, league_id, lang.into())
         .await
-        .unwrap()
-        .unwrap();
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
     let divisions = Division::by_league(&server_config.db_pool, league_id, lang.into())
         .await
-        .unwrap();
+        .or_app_error(lang)?;
+    let season_select = select_season(&server_config.db_pool, lang, league.id, select).await?;
     let html = DivisionListTemplate {
         locale: lang.into(),
         // TODO: add league_id here
         lang_links: other_lang_urls!(lang, DivisionListTemplate, "id" => league.id),
         league,
         divisions,
+        season_select,
+        lang,
+    };
+    Ok((StatusCode::OK, html))
+}
+
+async fn league_leaders_html(
+    State(server_config): State<ServerState>,
+    Path((lang, league_id)): Path<(SupportedLanguage, i32)>,
+    Query(query): Query<forms::LeadersQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let league = League::get(&server_config.db_pool, league_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let season_select = select_season(
+        &server_config.db_pool,
+        lang,
+        league.id,
+        forms::SeasonSelect { season: query.season },
+    )
+    .await?;
+    let min_games = query.min_games.unwrap_or(0).max(0);
+    let mut leaders = league
+        .leaders(&server_config.db_pool, season_select.selected, min_games)
+        .await
+        .or_app_error(lang)?;
+    query.sort.sort(&mut leaders);
+    let html = LeagueLeadersTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, LeagueLeadersTemplate, "id" => league.id),
+        league,
+        leaders: LeadersTableTemplate {
+            locale: lang.into(),
+            leaders,
+            season_select,
+            min_games,
+            sort: query.sort,
+            lang,
+        },
+        lang,
+    };
+    Ok((StatusCode::OK, html))
+}
+
+async fn division_leaders_html(
+    State(server_config): State<ServerState>,
+    Path((lang, division_id)): Path<(SupportedLanguage, i32)>,
+    Query(query): Query<forms::LeadersQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let division = Division::get(&server_config.db_pool, division_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let season_select = select_season(
+        &server_config.db_pool,
+        lang,
+        division.league,
+        forms::SeasonSelect { season: query.season },
+    )
+    .await?;
+    let min_games = query.min_games.unwrap_or(0).max(0);
+    let mut leaders = division
+        .leaders(&server_config.db_pool, season_select.selected, min_games)
+        .await
+        .or_app_error(lang)?;
+    query.sort.sort(&mut leaders);
+    let html = DivisionLeadersTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, DivisionLeadersTemplate, "id" => division.id),
+        division,
+        leaders: LeadersTableTemplate {
+            locale: lang.into(),
+            leaders,
+            season_select,
+            min_games,
+            sort: query.sort,
+            lang,
+        },
+        lang,
+    };
+    Ok((StatusCode::OK, html))
+}
+
+async fn season_archive_html(
+    State(server_config): State<ServerState>,
+    Path((lang, league_id)): Path<(SupportedLanguage, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let league = League::get(&server_config.db_pool, league_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let divisions = Division::by_league(&server_config.db_pool, league.id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let today = chrono::Utc::now().date_naive();
+    let mut seasons = Vec::new();
+    for season in Season::for_league(&server_config.db_pool, league.id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .into_iter()
+        .filter(|season| season.has_ended(today))
+    {
+        let mut archived = Vec::new();
+        for division in &divisions {
+            let standings = division
+                .standings(&server_config.db_pool, Some(season.id), lang.into())
+                .await
+                .or_app_error(lang)?;
+            // divisions which did not play that season
+            if standings.is_empty() {
+                continue;
+            }
+            archived.push(ArchivedStandings {
+                division_id: division.id,
+                division_name: division.name.clone(),
+                standings: IihfTeamStatsTableTemplate {
+                    locale: lang.into(),
+                    standings,
+                    lang,
+                },
+            });
+        }
+        seasons.push((season, archived));
+    }
+    let html = SeasonArchiveTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, SeasonArchiveTemplate, "id" => league.id),
+        league,
+        seasons,
+        lang,
+    };
+    Ok((StatusCode::OK, html))
+}
+
+async fn team_html(
+    State(server_config): State<ServerState>,
+    Path((lang, team_id)): Path<(SupportedLanguage, i32)>,
+    Query(select): Query<forms::SeasonSelect>,
+) -> Result<impl IntoResponse, AppError> {
+    let team = Team::get(&server_config.db_pool, team_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let division = Division::get(&server_config.db_pool, team.division, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let season_select = select_season(&server_config.db_pool, lang, division.league, select).await?;
+    let season_id = season_select.selected;
+    let standing = team
+        .standing(&server_config.db_pool, season_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let totals = team
+        .totals(&server_config.db_pool, season_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let roster = team
+        .roster(&server_config.db_pool, season_id)
+        .await
+        .or_app_error(lang)?;
+    let schedule = team
+        .schedule(&server_config.db_pool, season_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let team_template = TeamPageTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, TeamPageTemplate, "id" => team_id),
+        team,
+        division,
+        season_select,
+        record: IihfTeamStatsTableTemplate {
+            locale: lang.into(),
+            standings: standing.into_iter().collect(),
+            lang,
+        },
+        totals: TeamGameStatsTemplate {
+            locale: lang.into(),
+            teams: vec![totals],
+        },
+        roster,
+        schedule,
         lang,
     };
-    (StatusCode::OK, html)
+    Ok((StatusCode::OK, team_template))
 }
 
 async fn games_for_division_html(
     State(server_config): State<ServerState>,
     Path((lang, division_id)): Path<(SupportedLanguage, i32)>,
-) -> impl IntoResponse {
+    Query(select): Query<forms::SeasonSelect>,
+) -> Result<impl IntoResponse, AppError> {
     let division = Division::get(&server_config.db_pool, division_id, lang.into())
         .await
-        .unwrap()
-        .unwrap();
-    let games = Game::by_division(&server_config.db_pool, division.id, lang.into())
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let season_select = select_season(&server_config.db_pool, lang, division.league, select).await?;
+    let season_id = season_select.selected;
+    let mut games = Game::by_division(&server_config.db_pool, division.id, lang.into())
         .await
-        .unwrap();
-    let iihf_stats = division
-        .iihf_stats(&server_config.db_pool, lang.into())
+        .or_app_error(lang)?;
+    if let Some(season) = season_select.selected_season() {
+        games.retain(|game| season.contains(game.start_at.date_naive()));
+    }
+    let points_system = PointsSystem::for_division(&server_config.db_pool, division.id)
         .await
-        .unwrap();
+        .or_app_error(lang)?;
+    let standings = division
+        .standings(&server_config.db_pool, season_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let teams = division
+        .team_stats(&server_config.db_pool, season_id, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let players = division
+        .player_stats(&server_config.db_pool, season_id)
+        .await
+        .or_app_error(lang)?;
+    let goalies = division
+        .goalie_stats(&server_config.db_pool, season_id)
+        .await
+        .or_app_error(lang)?;
     let games_template = GameListTemplate {
         locale: lang.into(),
         lang_links: other_lang_urls!(lang, GameListTemplate, "id" => division_id),
         division,
+        season_select,
+        points_system,
         iihf_team_stats_table: IihfTeamStatsTableTemplate {
             locale: lang.into(),
-            iihf_stats,
+            standings,
+            lang,
+        },
+        team_stats: TeamGameStatsTemplate {
+            locale: lang.into(),
+            teams,
+        },
+        player_stats: IndividualGamePointsTableTemplate {
+            locale: lang.into(),
+            players,
+        },
+        goalie_stats: GoalieStatsTableTemplate {
+            locale: lang.into(),
+            goalies,
         },
         games,
         lang,
     };
-    (StatusCode::OK, games_template)
+    Ok((StatusCode::OK, games_template))
 }
 async fn score_for_game_html(
     State(server_config): State<ServerState>,
     Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, AppError> {
     let game = Game::get(&server_config.db_pool, game_id, lang.into())
         .await
-        .unwrap()
-        .unwrap();
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
     let division = Division::get(&server_config.db_pool, game.division, lang.into())
         .await
-        .unwrap()
-        .unwrap();
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let home_team = Team::get(&server_config.db_pool, game.team_home, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let away_team = Team::get(&server_config.db_pool, game.team_away, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
     let pbp = game
         .play_by_play(&server_config.db_pool, lang.into())
         .await
-        .unwrap();
+        .or_app_error(lang)?;
     let score = game
         .score(&server_config.db_pool, lang.into())
         .await
-        .unwrap();
+        .or_app_error(lang)?;
     let score_html = TeamGameStatsTemplate {
         locale: lang.into(),
         teams: score,
     };
-    let goal_details = game.box_score(&server_config.db_pool).await.unwrap();
+    let goal_details = game
+        .box_score(&server_config.db_pool)
+        .await
+        .or_app_error(lang)?;
     let goal_details_html = IndividualGamePointsTableTemplate {
         locale: lang.into(),
         players: goal_details,
@@ -470,12 +1468,36 @@ async fn score_for_game_html(
     let box_score = game
         .goals(&server_config.db_pool, lang.into())
         .await
-        .unwrap();
+        .or_app_error(lang)?;
     let box_score_html = BoxScoreTemplate {
         locale: lang.into(),
         goals: box_score,
         lang,
     };
+    let goalies = game
+        .goalie_stats(&server_config.db_pool)
+        .await
+        .or_app_error(lang)?;
+    let goalie_stats_html = GoalieStatsTableTemplate {
+        locale: lang.into(),
+        goalies,
+    };
+    let penalties = game
+        .penalties(&server_config.db_pool, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let penalties_html = PenaltySummaryTemplate {
+        locale: lang.into(),
+        penalties,
+    };
+    let attempts = game
+        .shootout(&server_config.db_pool, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let shootout_html = ShootoutSummaryTemplate {
+        locale: lang.into(),
+        attempts,
+    };
     let pbp_html = ShotsTableTemplate {
         locale: lang.into(),
         shots: pbp,
@@ -486,13 +1508,395 @@ async fn score_for_game_html(
         lang_links: other_lang_urls!(lang, GameScorePageTemplate, "id" => game_id),
         division,
         game,
+        home_team,
+        away_team,
         box_score: box_score_html,
         team_stats: score_html,
         individual_stats: goal_details_html,
+        goalie_stats: goalie_stats_html,
+        penalties: penalties_html,
+        shootout: shootout_html,
         play_by_play: pbp_html,
         lang,
     };
-    (StatusCode::OK, game_template)
+    Ok((StatusCode::OK, game_template))
+}
+
+/// A stream of server-sent events for a game; one `shot` event is sent for each shot recorded.
+async fn live_game_events(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let mut receiver = server_config.shot_events.subscribe();
+    let stream = async_stream::stream! {
+        loop {
+            let inserted = match receiver.recv().await {
+                Ok(inserted) if inserted.game == game.id => inserted,
+                // other games, or too many shots at once; the next event still has the latest score
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            match live::game_update(&server_config.db_pool, inserted, lang).await {
+                Ok(update) => yield Ok::<_, Infallible>(
+                    Event::default()
+                        .event("shot")
+                        .json_data(update)
+                        .expect("A game update can always be serialized."),
+                ),
+                Err(e) => log::error!("Unable to load shot {} for the live feed: {e}", inserted.shot),
+            }
+        }
+    };
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// A stream of server-sent events with an `announcement` event for each goal scored in any game.
+async fn goal_announcement_events(
+    State(server_config): State<ServerState>,
+    Path(lang): Path<SupportedLanguage>,
+) -> impl IntoResponse {
+    let mut receiver = server_config.announcements.subscribe();
+    let stream = async_stream::stream! {
+        loop {
+            match receiver.recv().await {
+                Ok(announcement) => if let Some(text) = announcement.text.get(&lang) {
+                    yield Ok::<_, Infallible>(
+                        Event::default()
+                            .event("announcement")
+                            .id(announcement.shot.to_string())
+                            .data(text),
+                    );
+                },
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    };
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Makes sure the scorekeeper keeps score in the league of the game; see [`auth::Authorized::has_role_in`].
+async fn check_scorekeeper_league(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    scorekeeper: &auth::Authorized<auth::Scorekeeper>,
+    game: &Game,
+) -> Result<(), AppError> {
+    let division = Division::get(pool, game.division, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    if scorekeeper
+        .has_role_in(pool, division.league)
+        .await
+        .or_app_error(lang)?
+    {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(lang))
+    }
+}
+
+async fn scorekeeper_page<'a>(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    game: Game,
+    errors: Vec<String>,
+) -> Result<ScorekeeperPageTemplate<'a>, AppError> {
+    let roster = game.roster(pool, lang.into()).await.or_app_error(lang)?;
+    let periods = game.periods(pool).await.or_app_error(lang)?;
+    let infractions = Infraction::all(pool, lang.into()).await.or_app_error(lang)?;
+    let penalties = game.penalties(pool, lang.into()).await.or_app_error(lang)?;
+    let attempts = game.shootout(pool, lang.into()).await.or_app_error(lang)?;
+    let pbp = game.play_by_play(pool, lang.into()).await.or_app_error(lang)?;
+    let rules = ClassificationRule::by_division(pool, game.division)
+        .await
+        .or_app_error(lang)?;
+    let lineup_problems = classification::validate(&roster, &rules);
+    let home_team = Team::get(pool, game.team_home, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let away_team = Team::get(pool, game.team_away, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    Ok(ScorekeeperPageTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, ScorekeeperPageTemplate, "id" => game.id),
+        game,
+        roster,
+        periods,
+        infractions,
+        penalty_minutes: &forms::PENALTY_MINUTES,
+        full_strength: forms::FULL_STRENGTH,
+        min_skaters: forms::MIN_SKATERS,
+        errors,
+        lineup_problems,
+        home_team,
+        away_team,
+        penalties: PenaltySummaryTemplate {
+            locale: lang.into(),
+            penalties,
+        },
+        shootout: ShootoutSummaryTemplate {
+            locale: lang.into(),
+            attempts,
+        },
+        play_by_play: ShotsTableTemplate {
+            locale: lang.into(),
+            shots: pbp,
+            lang,
+        },
+        lang,
+    })
+}
+
+async fn scorekeeper_html(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+) -> Result<impl IntoResponse, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let scorekeeper_template = scorekeeper_page(&server_config.db_pool, lang, game, Vec::new()).await?;
+    Ok((StatusCode::OK, scorekeeper_template))
+}
+
+async fn record_shot(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+    Form(shot): Form<forms::NewShot>,
+) -> Result<Response, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let roster = game.roster(&server_config.db_pool, lang.into()).await.or_app_error(lang)?;
+    let periods = game.periods(&server_config.db_pool).await.or_app_error(lang)?;
+    match shot.validate(&roster, &periods) {
+        Ok(new_shot) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            new_shot.insert(&mut conn).await.or_app_error(lang)?;
+            Ok(Redirect::to(&ScorekeeperPageTemplate::lang_link(lang, game_id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let scorekeeper_template = scorekeeper_page(&server_config.db_pool, lang, game, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, scorekeeper_template).into_response())
+        }
+    }
+}
+
+async fn record_penalty(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+    Form(penalty): Form<forms::NewPenalty>,
+) -> Result<Response, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let roster = game.roster(&server_config.db_pool, lang.into()).await.or_app_error(lang)?;
+    let periods = game.periods(&server_config.db_pool).await.or_app_error(lang)?;
+    let infractions = Infraction::all(&server_config.db_pool, lang.into())
+        .await
+        .or_app_error(lang)?;
+    match penalty.validate(&roster, &periods, &infractions) {
+        Ok(new_penalty) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            new_penalty.insert(&mut conn).await.or_app_error(lang)?;
+            Ok(Redirect::to(&ScorekeeperPageTemplate::lang_link(lang, game_id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let scorekeeper_template = scorekeeper_page(&server_config.db_pool, lang, game, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, scorekeeper_template).into_response())
+        }
+    }
+}
+
+/// The game and one of its teams, or not found if the team does not play in the game.
+async fn game_and_team(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    game_id: i32,
+    team_id: i32,
+) -> Result<(Game, Team), AppError> {
+    let game = Game::get(pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    let team = Team::get(pool, team_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .filter(|team| team.id == game.team_home || team.id == game.team_away)
+        .or_not_found(lang)?;
+    Ok((game, team))
+}
+
+async fn lineup_page<'a>(
+    pool: &PgPool,
+    lang: SupportedLanguage,
+    game: Game,
+    team: Team,
+    errors: Vec<String>,
+) -> Result<LineupTemplate<'a>, AppError> {
+    let roster = game.roster(pool, lang.into()).await.or_app_error(lang)?;
+    let mut players = Player::all(pool).await.or_app_error(lang)?;
+    players.retain(|player| !roster.iter().any(|dressed| dressed.player_id == player.id));
+    players.sort_by(|a, b| (&a.last_name, &a.first_names).cmp(&(&b.last_name, &b.first_names)));
+    let positions = Position::all(pool).await.or_app_error(lang)?;
+    let rules = ClassificationRule::by_division(pool, game.division)
+        .await
+        .or_app_error(lang)?;
+    let lineup: Vec<RosterPlayer> = roster
+        .into_iter()
+        .filter(|dressed| dressed.team_id == team.id)
+        .collect();
+    let lineup_problems = classification::validate(&lineup, &rules);
+    Ok(LineupTemplate {
+        locale: lang.into(),
+        lang_links: other_lang_urls!(lang, LineupTemplate, "id" => game.id, "team" => team.id),
+        game,
+        team,
+        lineup,
+        players,
+        positions,
+        max_player_number: forms::MAX_PLAYER_NUMBER,
+        errors,
+        lineup_problems,
+        lang,
+    })
+}
+
+async fn lineup_html(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id, team_id)): Path<(SupportedLanguage, i32, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+) -> Result<impl IntoResponse, AppError> {
+    let (game, team) = game_and_team(&server_config.db_pool, lang, game_id, team_id).await?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let lineup_template = lineup_page(&server_config.db_pool, lang, game, team, Vec::new()).await?;
+    Ok((StatusCode::OK, lineup_template))
+}
+
+async fn add_to_lineup(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id, team_id)): Path<(SupportedLanguage, i32, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+    Form(entry): Form<forms::NewGamePlayer>,
+) -> Result<Response, AppError> {
+    let (game, team) = game_and_team(&server_config.db_pool, lang, game_id, team_id).await?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let roster = game.roster(&server_config.db_pool, lang.into()).await.or_app_error(lang)?;
+    let players = Player::all(&*server_config.db_pool).await.or_app_error(lang)?;
+    let positions = Position::all(&*server_config.db_pool).await.or_app_error(lang)?;
+    match entry.validate(game.id, team.id, &roster, &players, &positions) {
+        Ok(new_game_player) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            if let Err(e) = new_game_player.insert(&mut conn).await {
+                let key = forms::lineup_conflict(&e).ok_or(e).or_app_error(lang)?;
+                let lineup_template = lineup_page(&server_config.db_pool, lang, game, team, vec![lang.lookup(key)]).await?;
+                return Ok((StatusCode::UNPROCESSABLE_ENTITY, lineup_template).into_response());
+            }
+            Ok(Redirect::to(&LineupTemplate::lang_link(lang, game.id, team.id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let lineup_template = lineup_page(&server_config.db_pool, lang, game, team, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, lineup_template).into_response())
+        }
+    }
+}
+
+async fn remove_from_lineup(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id, team_id)): Path<(SupportedLanguage, i32, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+    Form(removal): Form<forms::RemoveGamePlayer>,
+) -> Result<Response, AppError> {
+    let (game, team) = game_and_team(&server_config.db_pool, lang, game_id, team_id).await?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let roster = game.roster(&server_config.db_pool, lang.into()).await.or_app_error(lang)?;
+    let has_events = GamePlayer::has_events(&server_config.db_pool, removal.game_player)
+        .await
+        .or_app_error(lang)?;
+    match removal.validate(team.id, &roster, has_events) {
+        Ok(game_player_id) => {
+            GamePlayer::delete_row(&*server_config.db_pool, game_player_id)
+                .await
+                .or_app_error(lang)?;
+            Ok(Redirect::to(&LineupTemplate::lang_link(lang, game.id, team.id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let lineup_template = lineup_page(&server_config.db_pool, lang, game, team, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, lineup_template).into_response())
+        }
+    }
+}
+
+async fn copy_previous_lineup(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id, team_id)): Path<(SupportedLanguage, i32, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+) -> Result<Response, AppError> {
+    let (game, team) = game_and_team(&server_config.db_pool, lang, game_id, team_id).await?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let error = match GamePlayer::copy_previous_lineup(&server_config.db_pool, game.id, team.id).await {
+        Ok(0) => Some("error-nothing-to-copy"),
+        Ok(_) => None,
+        Err(e) => Some(forms::lineup_conflict(&e).ok_or(e).or_app_error(lang)?),
+    };
+    if let Some(key) = error {
+        let errors = vec![lang.lookup(key)];
+        let lineup_template = lineup_page(&server_config.db_pool, lang, game, team, errors).await?;
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, lineup_template).into_response());
+    }
+    Ok(Redirect::to(&LineupTemplate::lang_link(lang, game.id, team.id).href).into_response())
+}
+
+async fn record_shootout_attempt(
+    State(server_config): State<ServerState>,
+    Path((lang, game_id)): Path<(SupportedLanguage, i32)>,
+    scorekeeper: auth::Authorized<auth::Scorekeeper>,
+    Form(attempt): Form<forms::NewShootoutAttempt>,
+) -> Result<Response, AppError> {
+    let game = Game::get(&server_config.db_pool, game_id, lang.into())
+        .await
+        .or_app_error(lang)?
+        .or_not_found(lang)?;
+    check_scorekeeper_league(&server_config.db_pool, lang, &scorekeeper, &game).await?;
+    let roster = game.roster(&server_config.db_pool, lang.into()).await.or_app_error(lang)?;
+    let attempts = game
+        .shootout(&server_config.db_pool, lang.into())
+        .await
+        .or_app_error(lang)?;
+    let tied = game.is_tied(&server_config.db_pool).await.or_app_error(lang)?;
+    match attempt.validate(game.id, &roster, &attempts, tied) {
+        Ok(new_attempt) => {
+            let mut conn = server_config.db_pool.acquire().await.or_app_error(lang)?;
+            new_attempt.insert(&mut conn).await.or_app_error(lang)?;
+            Ok(Redirect::to(&ScorekeeperPageTemplate::lang_link(lang, game_id).href).into_response())
+        }
+        Err(errors) => {
+            let errors = errors.into_iter().map(|key| lang.lookup(key)).collect();
+            let scorekeeper_template = scorekeeper_page(&server_config.db_pool, lang, game, errors).await?;
+            Ok((StatusCode::UNPROCESSABLE_ENTITY, scorekeeper_template).into_response())
+        }
+    }
 }
 
 /*
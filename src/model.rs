@@ -1,7 +1,10 @@
+use crate::languages::SupportedLanguage;
 use chrono::serde::ts_seconds;
 use serde::{Deserialize, Serialize};
-use sqlx::types::chrono::{DateTime, Utc};
-use sqlx::FromRow;
+use sqlx::types::chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{Connection, FromRow};
+use std::collections::HashMap;
+use utoipa::ToSchema;
 
 pub trait TableName {
     const TABLE_NAME: &'static str;
@@ -30,6 +33,7 @@ pub struct Language {
 pub struct League {
     //#[ormx(default)]
     pub id: i32,
+    pub points_system: i32,
     pub name: Option<String>,
 }
 
@@ -45,6 +49,25 @@ pub struct Role {
     pub name: Option<String>,
 }
 
+impl Role {
+    pub async fn by_user(pool: &sqlx::PgPool, user_id: i32, lang: i32) -> Result<Vec<Role>, sqlx::Error> {
+        sqlx::query_as!(
+            Role,
+            r#"
+SELECT
+  roles.*,
+  role_name(roles.id, $2) AS name
+FROM roles
+JOIN users_roles ON users_roles.role=roles.id
+WHERE users_roles.user_id=$1;
+"#,
+            user_id, lang
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
+
 #[derive(FromRow, Serialize, Deserialize, Debug, ormx::Table)]
 #[ormx(table = "users", id = id, insertable, deletable)]
 pub struct User {
@@ -54,6 +77,18 @@ pub struct User {
 		pub pass_hash: String,
 }
 
+impl User {
+    pub async fn by_user_name(pool: &sqlx::PgPool, user_name: &str) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as!(
+            User,
+            "SELECT * FROM users WHERE user_name = $1;",
+            user_name
+        )
+        .fetch_optional(pool)
+        .await
+    }
+}
+
 /*
 #[derive(FromRow, Serialize, Deserialize, Debug, ormx::Patch)]
 #[ormx(table_name = "leagues", table = League, id = "id")]
@@ -74,6 +109,8 @@ pub struct Division {
     pub id: i32,
     #[table_names(get_many)]
     pub league: i32,
+    /// Overrides the points system of the league.
+    pub points_system: Option<i32>,
     pub name: Option<String>,
 }
 
@@ -83,6 +120,80 @@ pub struct NewDivision {
     pub league: i32,
 }
 
+/// How many points each outcome of a game is worth in the standings.
+#[derive(FromRow, Serialize, Deserialize, Debug, ormx::Table)]
+#[ormx(table = "points_systems", id = id, insertable, deletable)]
+pub struct PointsSystem {
+    #[ormx(default)]
+    pub id: i32,
+    /// A short name, like "3-2-1-0".
+    pub name: String,
+    pub reg_win: i32,
+    pub ot_win: i32,
+    pub ot_loss: i32,
+    pub reg_loss: i32,
+    pub tie: i32,
+    /// Without ties, a game tied after regulation gives no points until it is decided.
+    pub ties_allowed: bool,
+}
+
+impl PointsSystem {
+    /// The points system of a division, or of its league if the division does not set one.
+    pub async fn for_division(
+        pool: &sqlx::PgPool,
+        division_id: i32,
+    ) -> Result<PointsSystem, sqlx::Error> {
+        sqlx::query_as!(
+            PointsSystem,
+            "SELECT * FROM points_systems WHERE id = division_points_system($1);",
+            division_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
+
+/// A season of a league; its games are the ones which start between `start_date` and `end_date`.
+#[derive(FromRow, Serialize, Deserialize, Debug, NameTableName)]
+#[table_names(
+    table_name = "seasons",
+    name_func = "season_name",
+    name_table_name = "season_names",
+    name_table_name_fk = "season"
+)]
+pub struct Season {
+    pub id: i32,
+    #[table_names(get_many)]
+    pub league: i32,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub name: Option<String>,
+}
+
+impl Season {
+    /// The seasons of a league, from the latest to the earliest.
+    pub async fn for_league(
+        pool: &sqlx::PgPool,
+        league_id: i32,
+        lang: i32,
+    ) -> Result<Vec<Season>, sqlx::Error> {
+        let mut seasons = Season::by_league(pool, league_id, lang).await?;
+        seasons.sort_by(|a, b| b.start_date.cmp(&a.start_date));
+        Ok(seasons)
+    }
+    /// The season shown when none is chosen: the latest one which has started by `today`.
+    /// `seasons` must be ordered like [`Season::for_league`].
+    pub fn current(seasons: &[Season], today: NaiveDate) -> Option<&Season> {
+        seasons.iter().find(|season| season.start_date <= today)
+    }
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        (self.start_date..=self.end_date).contains(&date)
+    }
+    pub fn has_ended(&self, today: NaiveDate) -> bool {
+        self.end_date < today
+    }
+}
+
 #[derive(FromRow, Serialize, Deserialize, Debug, NameTableName)]
 //#[ormx(table = "teams", id = id, insertable, deletable)]
 #[table_names(
@@ -94,6 +205,7 @@ pub struct NewDivision {
 pub struct Team {
     //#[ormx(default)]
     pub id: i32,
+    #[table_names(get_many)]
     pub division: i32,
     pub image: Option<String>,
     pub name: Option<String>,
@@ -141,6 +253,54 @@ pub struct NewPlayer {
     pub height_cm: Option<i32>,
 }
 
+/// A player's sight classification, from `effective_date` until their next one.
+#[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
+#[ormx(table = "player_classifications", id = id, insertable, deletable)]
+pub struct PlayerClassification {
+    #[ormx(default)]
+    pub id: i32,
+    #[ormx(get_many(i32))]
+    pub player: i32,
+    /// `B1`, `B2`, `B3` or `SIGHTED`; see [`crate::classification`].
+    pub classification: String,
+    pub effective_date: NaiveDate,
+}
+
+impl PlayerClassification {
+    /// Every classification of a player, from the latest to the earliest.
+    pub async fn history(
+        pool: &sqlx::PgPool,
+        player_id: i32,
+    ) -> Result<Vec<PlayerClassification>, sqlx::Error> {
+        let mut history = PlayerClassification::by_player(pool, player_id).await?;
+        history.sort_by(|a, b| b.effective_date.cmp(&a.effective_date));
+        Ok(history)
+    }
+    /// The classification in effect on `date`, if the player was classified by then.
+    /// `history` must be ordered like [`PlayerClassification::history`].
+    pub fn effective_on(
+        history: &[PlayerClassification],
+        date: NaiveDate,
+    ) -> Option<&PlayerClassification> {
+        history.iter().find(|classified| classified.effective_date <= date)
+    }
+}
+
+/// How many players of a classification each team may dress for a game of a division.
+#[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
+#[ormx(table = "classification_rules", id = id, insertable, deletable)]
+pub struct ClassificationRule {
+    #[ormx(default)]
+    pub id: i32,
+    #[ormx(get_many(i32))]
+    pub division: i32,
+    pub classification: String,
+    /// `None` for no limit.
+    pub min_players: Option<i32>,
+    /// `None` for no limit.
+    pub max_players: Option<i32>,
+}
+
 #[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
 #[ormx(table = "shots", id = id, insertable, deletable)]
 pub struct Shot {
@@ -156,6 +316,13 @@ pub struct Shot {
     pub on_net: bool,
     pub assistant_second: Option<i32>,
     pub goal: bool,
+    /// Skaters on the ice for the shooter's team, not counting an extra attacker.
+    pub shooter_skaters: i32,
+    /// Skaters on the ice for the other team.
+    pub opponent_skaters: i32,
+    /// Whether the other team's goalie was pulled.
+    pub empty_net: bool,
+    #[ormx(default)]
     #[serde(with = "ts_seconds")]
     pub created_at: DateTime<Utc>,
 }
@@ -168,10 +335,180 @@ pub struct GamePlayer {
     pub team: i32,
     pub player: i32,
     pub position: i32,
+    #[ormx(get_many(i32))]
     pub game: i32,
+    pub player_number: i32,
+}
+
+impl GamePlayer {
+    /// Whether any shot, penalty or shootout attempt refers to this entry of the lineup.
+    pub async fn has_events(pool: &sqlx::PgPool, id: i32) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+SELECT
+  EXISTS (
+    SELECT shots.id
+    FROM shots
+    WHERE $1 IN (shots.shooter, shots.goalie, shots.blocker, shots.assistant, shots.assistant_second)
+  )
+  OR EXISTS (
+    SELECT penalties.id
+    FROM penalties
+    WHERE $1 IN (penalties.offender, penalties.served_by)
+  )
+  OR EXISTS (
+    SELECT shootout_attempts.id
+    FROM shootout_attempts
+    WHERE $1 IN (shootout_attempts.shooter, shootout_attempts.goalie)
+  );
+"#,
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await
+    }
+    /// Dresses a team for a game like it was for its previous game with a lineup.
+    /// Players who are already dressed for the game, and numbers which are already taken, are skipped.
+    /// Coaches keep their numbers, as they do not need unique ones.
+    /// Returns how many players were added.
+    pub async fn copy_previous_lineup(
+        pool: &sqlx::PgPool,
+        game_id: i32,
+        team_id: i32,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+INSERT INTO game_players
+  (team, game, player, position, player_number)
+SELECT
+  previous.team,
+  $1,
+  previous.player,
+  previous.position,
+  previous.player_number
+FROM game_players previous
+JOIN positions ON positions.id=previous.position
+WHERE previous.team=$2
+  AND previous.game=(
+    SELECT earlier_games.id
+    FROM games earlier_games
+    WHERE earlier_games.start_at < (SELECT games.start_at FROM games WHERE games.id=$1)
+      AND EXISTS (
+        SELECT earlier_players.id
+        FROM game_players earlier_players
+        WHERE earlier_players.game=earlier_games.id
+          AND earlier_players.team=$2
+      )
+    ORDER BY earlier_games.start_at DESC
+    LIMIT 1
+  )
+  AND NOT EXISTS (
+    SELECT dressed.id
+    FROM game_players dressed
+    WHERE dressed.game=$1
+      AND dressed.player=previous.player
+  )
+  AND (
+    positions.short_name = ANY($3)
+    OR NOT EXISTS (
+      SELECT dressed.id
+      FROM game_players dressed
+      JOIN positions dressed_positions ON dressed_positions.id=dressed.position
+      WHERE dressed.game=$1
+        AND dressed.team=$2
+        AND dressed.player_number=previous.player_number
+        AND dressed_positions.short_name <> ALL($3)
+    )
+    -- of players who shared a number in the previous game, only the first one is copied
+    AND NOT EXISTS (
+      SELECT twins.id
+      FROM game_players twins
+      JOIN positions twin_positions ON twin_positions.id=twins.position
+      WHERE twins.game=previous.game
+        AND twins.team=previous.team
+        AND twins.player_number=previous.player_number
+        AND twins.id < previous.id
+        AND twin_positions.short_name <> ALL($3)
+    )
+  );
+"#,
+        )
+        .bind(game_id)
+        .bind(team_id)
+        .bind(&STAFF_POSITIONS[..])
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
 }
 
-#[derive(FromRow, Deserialize, Serialize, Debug, NameTableName)]
+/// The short names of the positions of coaches, who are dressed for a game without playing:
+/// they do not need a unique number nor a classification.
+/// The `player_number_is_free` trigger on `game_players` has the same list.
+pub const STAFF_POSITIONS: [&str; 2] = ["HC", "AC"];
+
+#[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
+#[ormx(table = "positions", id = id, insertable, deletable)]
+pub struct Position {
+    pub id: i32,
+    pub name: String,
+    /// Localized with the `position` filter.
+    pub short_name: String,
+}
+
+impl Position {
+    pub fn is_staff(&self) -> bool {
+        STAFF_POSITIONS.contains(&self.short_name.as_str())
+    }
+}
+
+#[derive(FromRow, Serialize, Deserialize, Debug, NameTableName)]
+#[table_names(
+    table_name = "infractions",
+    name_func = "infraction_name",
+    name_table_name = "infraction_names",
+    name_table_name_fk = "infraction"
+)]
+pub struct Infraction {
+    pub id: i32,
+    pub short_name: String,
+    pub name: Option<String>,
+}
+
+#[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
+#[ormx(table = "penalties", id = id, insertable, deletable)]
+pub struct Penalty {
+    #[ormx(default)]
+    pub id: i32,
+    pub offender: i32,
+    pub served_by: i32,
+    pub infraction: i32,
+    pub minutes: i32,
+    pub period: i32,
+    pub period_time: i32,
+    #[ormx(default)]
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// One attempt of a shootout; kept apart from shots, so it does not count in the stats.
+#[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
+#[ormx(table = "shootout_attempts", id = id, insertable, deletable)]
+pub struct ShootoutAttempt {
+    #[ormx(default)]
+    pub id: i32,
+    pub game: i32,
+    pub shooter: i32,
+    pub goalie: i32,
+    /// 1 for the first attempt of the shootout, counting both teams.
+    pub attempt_number: i32,
+    pub goal: bool,
+    #[ormx(default)]
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(FromRow, Deserialize, Serialize, Debug, NameTableName, ToSchema)]
 #[table_names(
     table_name = "games",
     name_func = "game_name",
@@ -186,10 +523,125 @@ pub struct Game {
     pub team_home: i32,
     pub team_away: i32,
     pub name: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
     pub start_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
     pub end_at: DateTime<Utc>,
 }
 
+/// A game to add or change, with its name in every supported language; see [`Game::insert`] and [`Game::update`].
+#[derive(Deserialize, Serialize, Debug, PartialEq, ToSchema)]
+pub struct InsertGame {
+    pub division: i32,
+    pub team_home: i32,
+    pub team_away: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub start_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub end_at: DateTime<Utc>,
+    pub names: HashMap<SupportedLanguage, String>,
+}
+
+impl Game {
+    /// Adds the game and its names; returns the id of the new game.
+    pub async fn insert(conn: &mut sqlx::PgConnection, game: &InsertGame) -> Result<i32, sqlx::Error> {
+        let mut tx = conn.begin().await?;
+        let id: i32 = sqlx::query_scalar(
+            r#"
+INSERT INTO games
+  (division, team_home, team_away, start_at, end_at)
+VALUES
+  ($1, $2, $3, $4, $5)
+RETURNING id;
+"#,
+        )
+        .bind(game.division)
+        .bind(game.team_home)
+        .bind(game.team_away)
+        .bind(game.start_at)
+        .bind(game.end_at)
+        .fetch_one(&mut tx)
+        .await?;
+        Self::save_names(&mut tx, id, &game.names).await?;
+        tx.commit().await?;
+        Ok(id)
+    }
+    /// Changes the teams, times and names of the game.
+    pub async fn update(
+        conn: &mut sqlx::PgConnection,
+        id: i32,
+        game: &InsertGame,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = conn.begin().await?;
+        sqlx::query(
+            r#"
+UPDATE games
+SET
+  division=$2,
+  team_home=$3,
+  team_away=$4,
+  start_at=$5,
+  end_at=$6
+WHERE id=$1;
+"#,
+        )
+        .bind(id)
+        .bind(game.division)
+        .bind(game.team_home)
+        .bind(game.team_away)
+        .bind(game.start_at)
+        .bind(game.end_at)
+        .execute(&mut tx)
+        .await?;
+        Self::save_names(&mut tx, id, &game.names).await?;
+        tx.commit().await
+    }
+    async fn save_names(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: i32,
+        names: &HashMap<SupportedLanguage, String>,
+    ) -> Result<(), sqlx::Error> {
+        for (lang, name) in names {
+            sqlx::query(
+                r#"
+INSERT INTO game_names
+  (game, language, name)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT (game, language) DO UPDATE
+SET name=EXCLUDED.name;
+"#,
+            )
+            .bind(id)
+            .bind(i32::from(*lang))
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
+    }
+    /// The name of the game in each language it has one in; unlike `name`, it does not fall back to another language.
+    pub async fn names(
+        pool: &sqlx::PgPool,
+        id: i32,
+    ) -> Result<HashMap<SupportedLanguage, String>, sqlx::Error> {
+        let names = sqlx::query_as::<_, (SupportedLanguage, String)>(
+            "SELECT language, name FROM game_names WHERE game=$1;",
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await?;
+        Ok(names.into_iter().collect())
+    }
+    /// The teams with players dressed for the game.
+    pub async fn dressed_teams(pool: &sqlx::PgPool, id: i32) -> Result<Vec<i32>, sqlx::Error> {
+        sqlx::query_scalar("SELECT DISTINCT team FROM game_players WHERE game=$1;")
+            .bind(id)
+            .fetch_all(pool)
+            .await
+    }
+}
+
 #[derive(FromRow, Deserialize, Serialize, Debug, ormx::Table)]
 #[ormx(table = "periods", id = id, insertable, deletable)]
 pub struct Period {
@@ -203,8 +655,11 @@ pub struct Period {
 mod tests {
     use crate::languages::SupportedLanguage;
     use crate::model::{
-        Division, Game, GamePlayer, Language, League, Player, Shot, TableName, Team, User,
+        ClassificationRule, Division, Game, GamePlayer, InsertGame, InsertGamePlayer, Language, League, Player,
+        PlayerClassification, PointsSystem, Position, Season, Shot, TableName, Team, User,
     };
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    use std::collections::HashMap;
     use ormx::Table;
     use std::env;
     use strum::{EnumCount, IntoEnumIterator};
@@ -239,6 +694,133 @@ mod tests {
         })
     }
 
+    #[test]
+    fn check_division_points_system() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let points_system = PointsSystem::for_division(&pool, 1).await.unwrap();
+            assert_eq!(
+                points_system.name, "3-2-1-0 (ties)",
+                "Divisions without their own points system use the league's."
+            );
+            assert!(points_system.ties_allowed);
+            assert_eq!(points_system.tie, 2, "A tie is still worth what it was before points systems.");
+            assert!(PointsSystem::for_division(&pool, 0).await.is_err());
+        })
+    }
+
+    #[test]
+    fn check_current_season() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let seasons = Season::for_league(&pool, 1, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let current = Season::current(&seasons, day(2023, 6, 1)).unwrap();
+            assert_eq!(current.name.as_deref(), Some("2022"));
+            assert!(current.has_ended(day(2023, 6, 1)));
+            assert!(!current.has_ended(day(2022, 12, 31)));
+            assert!(
+                Season::current(&seasons, day(2021, 6, 1)).is_none(),
+                "No season has started yet."
+            );
+        })
+    }
+
+    #[test]
+    fn check_classification_history() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let history = PlayerClassification::history(&pool, 2).await.unwrap();
+            let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let classified = PlayerClassification::effective_on(&history, day(2022, 3, 25)).unwrap();
+            assert_eq!(classified.classification, "B3");
+            assert!(
+                PlayerClassification::effective_on(&history, day(2021, 12, 31)).is_none(),
+                "The player was not classified yet."
+            );
+        })
+    }
+
+    #[test]
+    fn check_game_schedule() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let mut tx = pool.begin().await.unwrap();
+            let mut game = InsertGame {
+                division: 1,
+                team_home: 1,
+                team_away: 2,
+                start_at: Utc.with_ymd_and_hms(2023, 9, 1, 22, 0, 0).unwrap(),
+                end_at: Utc.with_ymd_and_hms(2023, 9, 1, 23, 30, 0).unwrap(),
+                names: HashMap::from([
+                    (SupportedLanguage::English, "Game 5".to_string()),
+                    (SupportedLanguage::French, "Match 5".to_string()),
+                ]),
+            };
+            let id = Game::insert(&mut tx, &game).await.unwrap();
+            // rescheduled past midnight, with the teams switched
+            game.team_home = 2;
+            game.team_away = 1;
+            game.start_at = Utc.with_ymd_and_hms(2023, 9, 1, 23, 0, 0).unwrap();
+            game.end_at = Utc.with_ymd_and_hms(2023, 9, 2, 0, 30, 0).unwrap();
+            game.names
+                .insert(SupportedLanguage::French, "Match cinq".to_string());
+            Game::update(&mut tx, id, &game).await.unwrap();
+            let saved: (i32, i32, DateTime<Utc>) =
+                sqlx::query_as("SELECT team_home, team_away, start_at FROM games WHERE id=$1;")
+                    .bind(id)
+                    .fetch_one(&mut tx)
+                    .await
+                    .unwrap();
+            assert_eq!(saved, (2, 1, game.start_at));
+            let names: Vec<(i32, String)> = sqlx::query_as(
+                "SELECT language, name FROM game_names WHERE game=$1 ORDER BY language;",
+            )
+            .bind(id)
+            .fetch_all(&mut tx)
+            .await
+            .unwrap();
+            assert_eq!(
+                names,
+                vec![(1, "Game 5".to_string()), (2, "Match cinq".to_string())]
+            );
+            let against_itself = sqlx::query("UPDATE games SET team_away=team_home WHERE id=$1;")
+                .bind(id)
+                .execute(&mut tx)
+                .await;
+            assert!(against_itself.is_err(), "A team can not play against itself.");
+        })
+    }
+
+    #[test]
+    fn check_player_numbers_are_unique() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let mut tx = pool.begin().await.unwrap();
+            // Tait Hoyem already wears number 3 for team 1 in game 1.
+            let mut entry = InsertGamePlayer {
+                team: 1,
+                player: 6,
+                position: 7,
+                game: 1,
+                player_number: 3,
+            };
+            assert!(
+                entry.insert(&mut tx).await.is_ok(),
+                "Coaches do not need a number of their own."
+            );
+            entry.player = 20;
+            entry.position = 1;
+            let err = entry.insert(&mut tx).await.unwrap_err();
+            assert_eq!(
+                crate::forms::lineup_conflict(&err),
+                Some("error-player-number-taken")
+            );
+        })
+    }
+
     /// A simple function to connect to the database.
     async fn db_connect() -> sqlx::PgPool {
         let db_url = env::var("DATABASE_URL")
@@ -292,9 +874,14 @@ mod tests {
     generate_select_test_lang!(Division, select_division);
     generate_select_test_lang!(Team, select_team);
     generate_select_test_lang!(Game, select_game);
+    generate_select_test_lang!(Season, select_season);
     generate_select_test!(GamePlayer, selec_game_player);
     generate_select_test!(Player, select_player);
     generate_select_test!(User, select_user);
     generate_select_test!(Shot, select_shot);
     generate_select_test!(Language, select_lang);
+    generate_select_test!(PointsSystem, select_points_system);
+    generate_select_test!(PlayerClassification, select_player_classification);
+    generate_select_test!(ClassificationRule, select_classification_rule);
+    generate_select_test!(Position, select_position);
 }
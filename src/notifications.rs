@@ -0,0 +1,232 @@
+use crate::filters::seconds_as_time;
+use crate::languages::SupportedLanguage;
+use crate::live::ShotInserted;
+use crate::views::{goal_notification, Notification};
+use crate::LOCALES;
+use askama::i18n::fluent_templates::Loader;
+use askama::i18n::Locale;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use crate::config::AnnouncementConfig;
+use std::path::PathBuf;
+use strum::IntoEnumIterator;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::broadcast;
+
+/// A goal announcement in every supported language.
+#[derive(Serialize, Debug, Clone)]
+pub struct GoalAnnouncement {
+    pub game: i32,
+    pub shot: i32,
+    pub text: HashMap<SupportedLanguage, String>,
+}
+
+/// Where goal announcements are sent.
+#[derive(Debug, Default)]
+pub struct Sinks {
+    /// Announcements are sent on this channel to the server-sent events stream.
+    pub sse: Option<broadcast::Sender<GoalAnnouncement>>,
+    /// The announcement is `POST`ed as JSON to this URL.
+    pub webhook_url: Option<String>,
+    /// One line per language is appended to this file.
+    pub log_file: Option<PathBuf>,
+}
+
+impl Sinks {
+    pub fn from_config(config: &AnnouncementConfig, sse: broadcast::Sender<GoalAnnouncement>) -> Self {
+        Sinks {
+            sse: config.sse.then_some(sse),
+            webhook_url: config.webhook_url.clone(),
+            log_file: config.log_file.clone(),
+        }
+    }
+}
+
+/// Looks up the localized name of a position or period, falling back to the (English) name from the database.
+fn localized_name(lang: SupportedLanguage, key: &str, fallback: &str) -> String {
+    LOCALES
+        .lookup(&lang.into(), key)
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Formats a goal announcement like "See Cats centre #15 Lafrance has scored!" in the given language.
+pub fn announcement(notification: &Notification, lang: SupportedLanguage) -> String {
+    let position = localized_name(
+        lang,
+        &format!("position-{}", notification.position_short_name),
+        &notification.position,
+    );
+    let period = localized_name(
+        lang,
+        &format!("period-{}", notification.period_short_name),
+        &notification.period_name,
+    );
+    let time = seconds_as_time(&notification.period_time_left)
+        .expect("Formatting a time can not fail.");
+    Into::<Locale>::into(lang)
+        .translate(
+            "goal-announcement",
+            hashmap_macro::hashmap![
+                "team" => notification.scorer_team_name.clone().into(),
+                "position" => position.into(),
+                "number" => notification.scorer_number.into(),
+                "name" => notification.scorer_last_name.clone().into(),
+                "time" => time.into(),
+                "period" => period.into()
+            ],
+        )
+        .expect("Unable to find key goal-announcement.")
+}
+
+/// Builds the announcement for a shot, or `None` if the shot was not a goal.
+pub async fn goal_announcement(
+    pool: &PgPool,
+    inserted: ShotInserted,
+) -> Result<Option<GoalAnnouncement>, sqlx::Error> {
+    let mut text = HashMap::new();
+    for lang in SupportedLanguage::iter() {
+        let notification = match goal_notification(pool, inserted.shot, lang.into()).await {
+            Ok(notification) => notification,
+            Err(sqlx::Error::RowNotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        text.insert(lang, announcement(&notification, lang));
+    }
+    Ok(Some(GoalAnnouncement {
+        game: inserted.game,
+        shot: inserted.shot,
+        text,
+    }))
+}
+
+async fn append_to_log(path: &PathBuf, announcement: &GoalAnnouncement) -> std::io::Result<()> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    let now = chrono::Utc::now().to_rfc3339();
+    for lang in SupportedLanguage::iter() {
+        if let Some(text) = announcement.text.get(&lang) {
+            let line = format!("{now}\t{}\t{}\t{lang}\t{text}\n", announcement.game, announcement.shot);
+            file.write_all(line.as_bytes()).await?;
+        }
+    }
+    file.flush().await
+}
+
+/// Announces every goal recorded to all configured sinks.
+/// A failing sink is logged, and does not stop the others.
+pub async fn run(pool: PgPool, mut shots: broadcast::Receiver<ShotInserted>, sinks: Sinks) {
+    let client = reqwest::Client::new();
+    loop {
+        let inserted = match shots.recv().await {
+            Ok(inserted) => inserted,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                log::warn!("{skipped} shots were not checked for goal announcements.");
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        let announcement = match goal_announcement(&pool, inserted).await {
+            Ok(Some(announcement)) => announcement,
+            Ok(None) => continue,
+            Err(e) => {
+                log::error!("Unable to build the announcement for shot {}: {e}", inserted.shot);
+                continue;
+            }
+        };
+        if let Some(ref sse) = sinks.sse {
+            // an error only means nobody is listening right now
+            drop(sse.send(announcement.clone()));
+        }
+        if let Some(ref url) = sinks.webhook_url {
+            let response = client
+                .post(url)
+                .json(&announcement)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status);
+            if let Err(e) = response {
+                log::error!("Unable to send the announcement for shot {} to the webhook: {e}", inserted.shot);
+            }
+        }
+        if let Some(ref path) = sinks.log_file {
+            if let Err(e) = append_to_log(path, &announcement).await {
+                log::error!("Unable to write the announcement for shot {} to {}: {e}", inserted.shot, path.display());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::languages::SupportedLanguage;
+    use crate::live::ShotInserted;
+    use crate::notifications::{announcement, goal_announcement};
+    use crate::views::Notification;
+    use std::env;
+
+    fn notification() -> Notification {
+        Notification {
+            game_id: 1,
+            scorer_first_names: "Maurice Clement".to_string(),
+            scorer_last_name: "Lafrance".to_string(),
+            scorer_number: 15,
+            position: "Center".to_string(),
+            position_short_name: "C".to_string(),
+            scorer_team_name: "See Cats".to_string(),
+            period_name: "third".to_string(),
+            period_short_name: "3".to_string(),
+            period_time_left: 503,
+        }
+    }
+
+    #[test]
+    fn check_announcement_text() {
+        assert_eq!(
+            announcement(&notification(), SupportedLanguage::English),
+            "See Cats centre #15 Lafrance has scored! Time of the goal: 08:23 in the third period."
+        );
+        assert_eq!(
+            announcement(&notification(), SupportedLanguage::French),
+            "But des See Cats! Centre #15 Lafrance a marqué à 08:23 de la troisième période."
+        );
+    }
+
+    #[test]
+    fn check_unknown_period_falls_back() {
+        let mut notification = notification();
+        notification.period_short_name = "10OT".to_string();
+        notification.period_name = "tenth overtime".to_string();
+        assert!(announcement(&notification, SupportedLanguage::English).ends_with("in the tenth overtime."));
+    }
+
+    #[test]
+    fn check_goal_announcement() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let goal = goal_announcement(&pool, ShotInserted { game: 1, shot: 20 })
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(goal.text.len(), 2);
+            let not_a_goal = goal_announcement(&pool, ShotInserted { game: 1, shot: 1 })
+                .await
+                .unwrap();
+            assert!(not_a_goal.is_none(), "Shots which are not goals should not be announced.");
+        })
+    }
+
+    /// A simple function to connect to the database.
+    async fn db_connect() -> sqlx::PgPool {
+        let db_url = env::var("DATABASE_URL")
+            .expect("DATABASE_URL environment variable must be set to run tests.");
+        sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&db_url)
+            .await
+            .expect("Active database connection must be made")
+    }
+}
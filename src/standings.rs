@@ -0,0 +1,340 @@
+use crate::views::{IihfStatsI64, TeamGameResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// What separated a team from the others it was tied with on points.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Tiebreak {
+    /// Points in the games between the tied teams.
+    HeadToHeadPoints,
+    /// Goal difference in the games between the tied teams.
+    HeadToHeadGoalDifference,
+    /// Goals scored in the games between the tied teams.
+    HeadToHeadGoalsScored,
+    /// Goal difference in every game.
+    GoalDifference,
+    /// Nothing separated the teams, so they share their rank.
+    Unresolved,
+}
+
+impl Tiebreak {
+    /// The key of the tiebreak's description in the translations.
+    pub fn key(self) -> &'static str {
+        match self {
+            Tiebreak::HeadToHeadPoints => "tiebreak-head-to-head-points",
+            Tiebreak::HeadToHeadGoalDifference => "tiebreak-head-to-head-goal-difference",
+            Tiebreak::HeadToHeadGoalsScored => "tiebreak-head-to-head-goals-scored",
+            Tiebreak::GoalDifference => "tiebreak-goal-difference",
+            Tiebreak::Unresolved => "tiebreak-unresolved",
+        }
+    }
+}
+
+/// The IIHF tiebreakers, in the order they are applied.
+const TIEBREAKERS: [Tiebreak; 4] = [
+    Tiebreak::HeadToHeadPoints,
+    Tiebreak::HeadToHeadGoalDifference,
+    Tiebreak::HeadToHeadGoalsScored,
+    Tiebreak::GoalDifference,
+];
+
+/// A team's place in the standings of a division.
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct Standing {
+    /// Teams which could not be separated share a rank, and the next rank is skipped.
+    pub rank: usize,
+    /// `None` unless the team was tied with another on points.
+    pub tiebreak: Option<Tiebreak>,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub team: IihfStatsI64,
+}
+impl Standing {
+    pub fn goal_difference(&self) -> i64 {
+        self.goals_for - self.goals_against
+    }
+}
+
+/// Groups teams with the same value, from the highest value to the lowest.
+/// Teams with the same value keep their order.
+fn group_by_value(mut values: Vec<(i32, i64)>) -> Vec<Vec<i32>> {
+    values.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut groups: Vec<(i64, Vec<i32>)> = Vec::new();
+    for (team_id, value) in values {
+        match groups.last_mut() {
+            Some((last, group)) if *last == value => group.push(team_id),
+            _ => groups.push((value, vec![team_id])),
+        }
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// The value of a tiebreaker for a team, among the teams of `group`.
+fn tiebreaker_value(
+    tiebreak: Tiebreak,
+    team_id: i32,
+    group: &[i32],
+    results: &[TeamGameResult],
+) -> i64 {
+    let games = results.iter().filter(|result| result.team_id == team_id);
+    let head_to_head = games
+        .clone()
+        .filter(|result| group.contains(&result.opponent_id));
+    match tiebreak {
+        Tiebreak::HeadToHeadPoints => head_to_head.map(|result| result.points).sum(),
+        Tiebreak::HeadToHeadGoalDifference => head_to_head
+            .map(|result| result.goals_for - result.goals_against)
+            .sum(),
+        Tiebreak::HeadToHeadGoalsScored => head_to_head.map(|result| result.goals_for).sum(),
+        Tiebreak::GoalDifference => games
+            .map(|result| result.goals_for - result.goals_against)
+            .sum(),
+        Tiebreak::Unresolved => 0,
+    }
+}
+
+/// Orders teams tied on points into places, best first.
+/// Whenever a tiebreaker splits the teams, the ones still tied start over with the first tiebreaker,
+/// counting only the games between themselves.
+/// Teams still tied after every tiebreaker share a place.
+fn break_tie(group: &[i32], results: &[TeamGameResult]) -> Vec<Vec<(i32, Tiebreak)>> {
+    for tiebreak in TIEBREAKERS {
+        let values: Vec<(i32, i64)> = group
+            .iter()
+            .map(|&team_id| (team_id, tiebreaker_value(tiebreak, team_id, group, results)))
+            .collect();
+        if values.iter().all(|(_, value)| *value == values[0].1) {
+            continue;
+        }
+        return group_by_value(values)
+            .into_iter()
+            .flat_map(|subgroup| match subgroup[..] {
+                [team_id] => vec![vec![(team_id, tiebreak)]],
+                _ => break_tie(&subgroup, results),
+            })
+            .collect();
+    }
+    vec![group
+        .iter()
+        .map(|&team_id| (team_id, Tiebreak::Unresolved))
+        .collect()]
+}
+
+/// Ranks the teams of a division by points, breaking ties with the IIHF rules:
+/// head-to-head points, head-to-head goal difference, head-to-head goals scored, then overall goal difference.
+/// `results` has the result of every game for both of its teams.
+pub fn rank(teams: Vec<IihfStatsI64>, results: &[TeamGameResult]) -> Vec<Standing> {
+    let points = teams.iter().map(|team| (team.team_id, team.points)).collect();
+    let mut places: Vec<Vec<(i32, Option<Tiebreak>)>> = Vec::new();
+    for group in group_by_value(points) {
+        if let [team_id] = group[..] {
+            places.push(vec![(team_id, None)]);
+        } else {
+            places.extend(break_tie(&group, results).into_iter().map(|place| {
+                place
+                    .into_iter()
+                    .map(|(team_id, tiebreak)| (team_id, Some(tiebreak)))
+                    .collect()
+            }));
+        }
+    }
+    let mut teams: HashMap<i32, IihfStatsI64> =
+        teams.into_iter().map(|team| (team.team_id, team)).collect();
+    let mut standings = Vec::with_capacity(teams.len());
+    for place in places {
+        let rank = standings.len() + 1;
+        for (team_id, tiebreak) in place {
+            let Some(team) = teams.remove(&team_id) else {
+                continue;
+            };
+            let games = results.iter().filter(|result| result.team_id == team_id);
+            standings.push(Standing {
+                rank,
+                tiebreak,
+                goals_for: games.clone().map(|result| result.goals_for).sum(),
+                goals_against: games.map(|result| result.goals_against).sum(),
+                team,
+            });
+        }
+    }
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::standings::{rank, Standing, Tiebreak};
+    use crate::views::{IihfStatsI64, TeamGameResult};
+
+    const A: i32 = 1;
+    const B: i32 = 2;
+    const C: i32 = 3;
+    const D: i32 = 4;
+
+    /// Both sides of a game, with 3 points for a win and 1 for a tie.
+    fn game(game_id: i32, home: (i32, i64), away: (i32, i64)) -> [TeamGameResult; 2] {
+        let points = |goals_for: i64, goals_against: i64| match goals_for.cmp(&goals_against) {
+            std::cmp::Ordering::Greater => 3,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Less => 0,
+        };
+        [
+            TeamGameResult {
+                game_id,
+                team_id: home.0,
+                opponent_id: away.0,
+                goals_for: home.1,
+                goals_against: away.1,
+                points: points(home.1, away.1),
+            },
+            TeamGameResult {
+                game_id,
+                team_id: away.0,
+                opponent_id: home.0,
+                goals_for: away.1,
+                goals_against: home.1,
+                points: points(away.1, home.1),
+            },
+        ]
+    }
+
+    /// Sums the results into the stats of each team, like `division_iihf_stats` does.
+    fn standings(games: &[[TeamGameResult; 2]]) -> Vec<Standing> {
+        let results: Vec<TeamGameResult> = games.iter().flatten().cloned().collect();
+        let mut team_ids: Vec<i32> = results.iter().map(|result| result.team_id).collect();
+        team_ids.sort_unstable();
+        team_ids.dedup();
+        let teams = team_ids
+            .into_iter()
+            .map(|team_id| {
+                let games: Vec<&TeamGameResult> =
+                    results.iter().filter(|result| result.team_id == team_id).collect();
+                let count = |points| games.iter().filter(|game| game.points == points).count() as i64;
+                IihfStatsI64 {
+                    team_name: Some(format!("Team {team_id}")),
+                    team_id,
+                    reg_wins: count(3),
+                    reg_losses: count(0),
+                    ot_wins: 0,
+                    ot_losses: 0,
+                    ties: count(1),
+                    points: games.iter().map(|game| game.points).sum(),
+                    pp_goals: 0,
+                    pp_opportunities: 0,
+                    pp_goals_against: 0,
+                    times_short_handed: 0,
+                }
+            })
+            .collect();
+        rank(teams, &results)
+    }
+
+    fn order(standings: &[Standing]) -> Vec<(i32, usize, Option<Tiebreak>)> {
+        standings
+            .iter()
+            .map(|standing| (standing.team.team_id, standing.rank, standing.tiebreak))
+            .collect()
+    }
+
+    #[test]
+    fn check_ranked_by_points() {
+        let standings = standings(&[
+            game(1, (A, 1), (B, 3)),
+            game(2, (B, 2), (C, 2)),
+            game(3, (C, 0), (A, 5)),
+        ]);
+        assert_eq!(order(&standings), vec![(B, 1, None), (A, 2, None), (C, 3, None)]);
+        assert_eq!(standings[1].goals_for, 6);
+        assert_eq!(standings[1].goal_difference(), 3);
+    }
+
+    #[test]
+    fn check_head_to_head_points() {
+        // B has the better goal difference, but lost to A
+        let standings = standings(&[
+            game(1, (A, 2), (B, 1)),
+            game(2, (C, 3), (A, 0)),
+            game(3, (B, 9), (D, 0)),
+            game(4, (C, 1), (D, 0)),
+        ]);
+        assert_eq!(
+            order(&standings),
+            vec![
+                (C, 1, None),
+                (A, 2, Some(Tiebreak::HeadToHeadPoints)),
+                (B, 3, Some(Tiebreak::HeadToHeadPoints)),
+                (D, 4, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_head_to_head_goal_difference() {
+        // every team beat one of the others, but C won by more
+        let standings = standings(&[
+            game(1, (A, 2), (B, 1)),
+            game(2, (B, 2), (C, 1)),
+            game(3, (C, 4), (A, 1)),
+        ]);
+        assert_eq!(
+            order(&standings),
+            vec![
+                (C, 1, Some(Tiebreak::HeadToHeadGoalDifference)),
+                (B, 2, Some(Tiebreak::HeadToHeadGoalDifference)),
+                (A, 3, Some(Tiebreak::HeadToHeadGoalDifference)),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_head_to_head_goals_scored() {
+        // every head-to-head goal difference is 0; A and C scored 5, B only 3
+        let standings = standings(&[
+            game(1, (A, 2), (B, 1)),
+            game(2, (B, 2), (C, 1)),
+            game(3, (C, 4), (A, 3)),
+        ]);
+        assert_eq!(
+            order(&standings),
+            vec![
+                (C, 1, Some(Tiebreak::HeadToHeadPoints)),
+                (A, 2, Some(Tiebreak::HeadToHeadPoints)),
+                (B, 3, Some(Tiebreak::HeadToHeadGoalsScored)),
+            ],
+            "A and C start over with only their own game, which C won."
+        );
+    }
+
+    #[test]
+    fn check_goal_difference() {
+        // A and B tied each other; A beat D by more
+        let standings = standings(&[
+            game(1, (A, 1), (B, 1)),
+            game(2, (A, 5), (D, 0)),
+            game(3, (B, 2), (D, 1)),
+        ]);
+        assert_eq!(
+            order(&standings),
+            vec![
+                (A, 1, Some(Tiebreak::GoalDifference)),
+                (B, 2, Some(Tiebreak::GoalDifference)),
+                (D, 3, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_unresolved_tie_shares_rank() {
+        let standings = standings(&[game(1, (A, 2), (B, 2)), game(2, (C, 0), (D, 0))]);
+        assert_eq!(
+            order(&standings),
+            vec![
+                (A, 1, Some(Tiebreak::Unresolved)),
+                (B, 1, Some(Tiebreak::Unresolved)),
+                (C, 3, Some(Tiebreak::Unresolved)),
+                (D, 3, Some(Tiebreak::Unresolved)),
+            ]
+        );
+    }
+}
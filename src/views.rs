@@ -1,15 +1,31 @@
 #![allow(dead_code)]
 
-use crate::model::{Division, Game, League, Player};
+use crate::model::{Division, Game, League, Player, Team};
+use crate::standings::{self, Standing};
 use serde::{Deserialize, Serialize};
+use sqlx::types::chrono::{DateTime, Utc};
 use sqlx::FromRow;
 use sqlx::PgPool;
+use utoipa::ToSchema;
 
-#[derive(FromRow, Deserialize, Serialize, Debug)]
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
 pub struct TeamStats {
     pub name: String,
     pub goals: i64,
+    /// Every shot taken, whether it was on net, missed or blocked.
     pub shots: i64,
+    pub shots_on_goal: i64,
+    pub missed_shots: i64,
+    /// Shots taken by this team which were blocked.
+    pub shots_blocked: i64,
+    /// Shots of the other team which this team blocked.
+    pub blocks: i64,
+}
+impl TeamStats {
+    /// Goals per shot on goal, in percent; `None` without any shot on goal.
+    pub fn shooting_percentage(&self) -> Option<f64> {
+        (self.shots_on_goal > 0).then(|| self.goals as f64 * 100.0 / self.shots_on_goal as f64)
+    }
 }
 
 #[derive(FromRow, Deserialize, Serialize, Debug)]
@@ -23,7 +39,7 @@ pub struct IihfStats {
     pub ties: i32,
     pub points: i32,
 }
-#[derive(FromRow, Deserialize, Serialize, Debug)]
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
 pub struct IihfStatsI64 {
     pub team_name: Option<String>,
     pub team_id: i32,
@@ -33,19 +49,26 @@ pub struct IihfStatsI64 {
     pub ot_losses: i64,
     pub ties: i64,
     pub points: i64,
+    /// Goals scored on the power play.
+    pub pp_goals: i64,
+    /// Penalties called against the other teams which left them short-handed.
+    pub pp_opportunities: i64,
+    /// Goals allowed while short-handed.
+    pub pp_goals_against: i64,
+    /// Penalties called against the team which left it short-handed.
+    pub times_short_handed: i64,
 }
-impl From<IihfStats> for IihfStatsI64 {
-    fn from(val: IihfStats) -> Self {
-        IihfStatsI64 {
-            team_name: val.team_name.clone(),
-            team_id: val.team_id,
-            reg_wins: val.reg_wins.into(),
-            reg_losses: val.reg_losses.into(),
-            ot_wins: val.ot_wins.into(),
-            ot_losses: val.ot_losses.into(),
-            ties: val.ties.into(),
-            points: val.points.into(),
-        }
+impl IihfStatsI64 {
+    /// The share of power plays scored on, in percent; `None` without any power play.
+    pub fn power_play_percentage(&self) -> Option<f64> {
+        (self.pp_opportunities > 0)
+            .then(|| self.pp_goals as f64 * 100.0 / self.pp_opportunities as f64)
+    }
+    /// The share of penalties killed without allowing a goal, in percent; `None` if the team was never short-handed.
+    pub fn penalty_kill_percentage(&self) -> Option<f64> {
+        (self.times_short_handed > 0).then(|| {
+            100.0 - self.pp_goals_against as f64 * 100.0 / self.times_short_handed as f64
+        })
     }
 }
 
@@ -56,24 +79,205 @@ pub struct IihfPoints {
     pub points: i32,
 }
 
+/// The result of one game for one of its teams, as counted in the standings.
+#[derive(FromRow, Deserialize, Serialize, Debug, Clone)]
+pub struct TeamGameResult {
+    pub game_id: i32,
+    pub team_id: i32,
+    pub opponent_id: i32,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub points: i64,
+}
+
 #[derive(FromRow, Deserialize, Serialize, Debug)]
 pub struct Notification {
+    pub game_id: i32,
     pub scorer_first_names: String,
     pub scorer_last_name: String,
     pub scorer_number: i32,
     pub position: String,
+    pub position_short_name: String,
     pub scorer_team_name: String,
     pub period_name: String,
+    pub period_short_name: String,
     pub period_time_left: i32,
 }
 
+/// A player dressed for a specific game, as found in `game_players`.
 #[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct RosterPlayer {
+    /// The `game_players` id; this is what shots refer to.
+    pub id: i32,
+    pub player_id: i32,
+    pub player_number: i32,
+    pub first_names: String,
+    pub last_name: String,
+    pub team_id: i32,
+    pub team_name: String,
+    pub position_short_name: String,
+    /// The player's classification on the day of the game, if they were classified by then.
+    pub classification: Option<String>,
+}
+
+/// A player who was dressed for a team, with the number, position and classification of their latest game.
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct TeamRosterPlayer {
+    pub player_id: i32,
+    pub first_names: String,
+    pub last_name: String,
+    pub player_number: i32,
+    pub position_short_name: String,
+    pub classification: Option<String>,
+    pub games_played: i64,
+}
+
+/// A game on a team's schedule, from the point of view of that team.
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct TeamGame {
+    pub game_id: i32,
+    pub name: Option<String>,
+    pub start_at: DateTime<Utc>,
+    pub home: bool,
+    pub opponent_id: i32,
+    pub opponent_name: Option<String>,
+    /// Whether any period has been played; the other fields are only the result once it has.
+    pub played: bool,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    /// See the `game_outcome` function.
+    pub outcome: String,
+}
+
+/// One game of a player's game log.
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct PlayerGame {
+    pub game_id: i32,
+    pub game_name: Option<String>,
+    pub start_at: DateTime<Utc>,
+    pub team_id: i32,
+    pub team_name: Option<String>,
+    pub opponent_id: i32,
+    pub opponent_name: Option<String>,
+    pub player_number: i32,
+    pub position_short_name: String,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+    pub shot_attempts: i64,
+    pub shots_on_goal: i64,
+}
+
+/// The stats of a player in one division; see [`player_stats_by_division`].
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct PlayerDivisionStats {
+    pub league_id: i32,
+    pub league_name: Option<String>,
+    pub division_id: i32,
+    pub division_name: Option<String>,
+    pub games_played: i64,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+    pub pim: i64,
+    pub shot_attempts: i64,
+    pub shots_on_goal: i64,
+}
+
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct PeriodDetails {
+    pub id: i32,
+    pub period_type: i32,
+    pub period_length: i32,
+    pub short_name: String,
+    /// `REG` for regulation time, `OT` for an overtime or `SO` for the shootout.
+    pub kind: String,
+}
+
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
 pub struct PlayerStats {
     pub first_names: String,
     pub last_name: String,
     pub goals: i64,
     pub assists: i64,
     pub points: i64,
+    /// Penalty minutes.
+    pub pim: i64,
+    /// Every shot taken, whether it was on net, missed or blocked.
+    pub shot_attempts: i64,
+    pub shots_on_goal: i64,
+    pub missed_shots: i64,
+    /// Shots taken by this player which were blocked.
+    pub shots_blocked: i64,
+    /// Shots of the other team which this player blocked.
+    pub blocks: i64,
+}
+impl PlayerStats {
+    /// Goals per shot on goal, in percent; `None` without any shot on goal.
+    pub fn shooting_percentage(&self) -> Option<f64> {
+        (self.shots_on_goal > 0).then(|| self.goals as f64 * 100.0 / self.shots_on_goal as f64)
+    }
+}
+
+/// A player's scoring over a league or a division; see [`league_leaders`] and [`division_leaders`].
+#[derive(FromRow, Deserialize, Serialize, Debug)]
+pub struct LeaderStats {
+    pub player_id: i32,
+    pub first_names: String,
+    pub last_name: String,
+    pub games_played: i64,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+    /// Penalty minutes.
+    pub pim: i64,
+    pub shot_attempts: i64,
+    pub shots_on_goal: i64,
+}
+impl LeaderStats {
+    /// Goals per shot on goal, in percent; `None` without any shot on goal.
+    pub fn shooting_percentage(&self) -> Option<f64> {
+        (self.shots_on_goal > 0).then(|| self.goals as f64 * 100.0 / self.shots_on_goal as f64)
+    }
+}
+
+/// The column the scoring leaders are ranked by, as in `?sort=goals`.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderSort {
+    #[default]
+    Points,
+    Goals,
+    Assists,
+    ShootingPercentage,
+}
+impl LeaderSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderSort::Points => "points",
+            LeaderSort::Goals => "goals",
+            LeaderSort::Assists => "assists",
+            LeaderSort::ShootingPercentage => "shooting_percentage",
+        }
+    }
+    /// Ranks the leaders, highest first; ties are broken by points, then goals.
+    /// Players without a shot on goal come last by shooting percentage.
+    pub fn sort(&self, leaders: &mut [LeaderStats]) {
+        leaders.sort_by(|a, b| {
+            let primary = match self {
+                LeaderSort::Points => b.points.cmp(&a.points),
+                LeaderSort::Goals => b.goals.cmp(&a.goals),
+                LeaderSort::Assists => b.assists.cmp(&a.assists),
+                LeaderSort::ShootingPercentage => b
+                    .shooting_percentage()
+                    .unwrap_or(-1.0)
+                    .total_cmp(&a.shooting_percentage().unwrap_or(-1.0)),
+            };
+            primary
+                .then_with(|| b.points.cmp(&a.points))
+                .then_with(|| b.goals.cmp(&a.goals))
+        });
+    }
 }
 
 pub async fn game_box_score(pool: &PgPool, game_id: i32) -> Result<Vec<PlayerStats>, sqlx::Error> {
@@ -82,6 +286,12 @@ SELECT
     COUNT(shots.id) AS points,
     COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
     COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+    COALESCE((SELECT SUM(minutes) FROM penalties WHERE offender=game_players.id), 0) AS pim,
+    (SELECT shot_attempts FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS shot_attempts,
+    (SELECT shots_on_goal FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS shots_on_goal,
+    (SELECT missed_shots FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS missed_shots,
+    (SELECT shots_blocked FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS shots_blocked,
+    (SELECT blocks FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS blocks,
     players.first_names,
     players.last_name
 FROM game_players
@@ -98,6 +308,8 @@ GROUP BY
     players.last_name,
     players.first_names
 HAVING COUNT(shots.id) > 0
+    OR EXISTS (SELECT 1 FROM penalties WHERE offender=game_players.id)
+    OR EXISTS (SELECT 1 FROM shots WHERE shooter=game_players.id OR blocker=game_players.id)
 ORDER BY
     points DESC,
     goals DESC;
@@ -127,18 +339,19 @@ pub async fn game_goals(
     game_players.player_number AS player_number,
     gp_assist.player_number AS first_assist_number,
     gp_assist_second.player_number AS second_assist_number,
-    team_name(teams.id, $1) AS team_name,
+    team_name(teams.id, $2) AS team_name,
     teams.id AS team_id,
     shots.period_time AS time_remaining,
     period_types.id AS period_id,
-    period_types.short_name AS period_short_name
+    period_types.short_name AS period_short_name,
+    goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net) AS strength
   FROM shots
   JOIN game_players ON game_players.id=shots.shooter
   JOIN players ON players.id=game_players.player
   LEFT JOIN game_players gp_assist ON gp_assist.id=shots.assistant
   LEFT JOIN players p_assist ON p_assist.id=gp_assist.player
-  LEFT JOIN game_players gp_assist_second ON gp_assist.id=shots.assistant_second
-  LEFT JOIN players p_assist_second ON p_assist.id=gp_assist_second.player
+  LEFT JOIN game_players gp_assist_second ON gp_assist_second.id=shots.assistant_second
+  LEFT JOIN players p_assist_second ON p_assist_second.id=gp_assist_second.player
   JOIN teams ON teams.id=game_players.team
   JOIN periods ON periods.id=shots.period
   JOIN period_types ON period_types.id=periods.period_type
@@ -179,7 +392,7 @@ pub async fn game_iihf_stats(
   JOIN teams
     ON teams.id=games.team_home
     OR teams.id=games.team_away
- WHERE games.id=4
+ WHERE games.id=$1
  GROUP BY teams.id,games.id;
   "#;
     sqlx::query_as::<_, IihfStats>(query)
@@ -188,10 +401,9 @@ pub async fn game_iihf_stats(
         .fetch_all(pool)
         .await
 }
-/// Returns the number of points using IIHF scoring rules for each team.
-/// NOTE: The algorithm used here requires that a 4th period is the "overtime";
-/// it does not check if there was only two periods, followed by an overtime.
-/// This should be sufficient for most.
+/// Returns the number of points for each team, using the points system of the game's division.
+/// Whether the game was decided in regulation, overtime or a shootout depends on the kind of period
+/// the deciding goal was scored in; see the `game_outcome` SQL function.
 pub async fn game_iihf_points(
     pool: &PgPool,
     game_id: i32,
@@ -200,7 +412,7 @@ pub async fn game_iihf_points(
     let query = r#"
   SELECT 
 		iihf_points(games.id, teams.id) AS points,
-    team_name(teams.id, $1) AS team_name,
+    team_name(teams.id, $2) AS team_name,
 		teams.id AS team_id
   FROM games
 	JOIN teams
@@ -225,6 +437,15 @@ pub async fn game_score(
   SELECT 
     COUNT(CASE WHEN shots.goal = true THEN shots.id END) AS goals,
     COUNT(shots.id) AS shots,
+    COUNT(CASE WHEN shots.on_net = true THEN shots.id END) AS shots_on_goal,
+    COUNT(CASE WHEN shots.on_net = false AND shots.blocker IS NULL THEN shots.id END) AS missed_shots,
+    COUNT(CASE WHEN shots.blocker IS NOT NULL THEN shots.id END) AS shots_blocked,
+    (SELECT COUNT(blocked.id)
+      FROM shots blocked
+      JOIN game_players blockers ON blockers.id=blocked.blocker
+      WHERE blockers.game=$1
+        AND blockers.team=teams.id
+    ) AS blocks,
     team_name(teams.id, $2) AS name
   FROM games
   JOIN periods ON periods.game=games.id
@@ -240,6 +461,94 @@ pub async fn game_score(
         .fetch_all(pool)
         .await
 }
+/// Shot totals of every team over all games of a division, or of one of its seasons; see [`game_score`].
+pub async fn division_team_stats(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+    lang: i32,
+) -> Result<Vec<TeamStats>, sqlx::Error> {
+    let query = r#"
+  SELECT
+    COUNT(CASE WHEN shots.goal = true THEN shots.id END) AS goals,
+    COUNT(shots.id) AS shots,
+    COUNT(CASE WHEN shots.on_net = true THEN shots.id END) AS shots_on_goal,
+    COUNT(CASE WHEN shots.on_net = false AND shots.blocker IS NULL THEN shots.id END) AS missed_shots,
+    COUNT(CASE WHEN shots.blocker IS NOT NULL THEN shots.id END) AS shots_blocked,
+    (SELECT COUNT(blocked.id)
+      FROM shots blocked
+      JOIN game_players blockers ON blockers.id=blocked.blocker
+      JOIN games blocker_games ON blocker_games.id=blockers.game
+      WHERE blocker_games.division=$1
+        AND game_in_season(blocker_games.id, $3)
+        AND blockers.team=teams.id
+    ) AS blocks,
+    team_name(teams.id, $2) AS name
+  FROM games
+  JOIN periods ON periods.game=games.id
+  JOIN shots ON shots.period=periods.id
+  JOIN game_players ON game_players.id=shots.shooter
+  JOIN teams ON teams.id=game_players.team
+  WHERE games.division=$1
+    AND game_in_season(games.id, $3)
+  GROUP BY teams.id
+  ORDER BY goals DESC;
+  "#;
+    sqlx::query_as::<_, TeamStats>(query)
+        .bind(division_id)
+        .bind(lang)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+}
+/// Points and shots of every player over all games of a division, or of one of its seasons; see [`game_box_score`].
+pub async fn division_player_stats(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+) -> Result<Vec<PlayerStats>, sqlx::Error> {
+    let query = r#"
+SELECT
+  COUNT(shots.id) AS points,
+  COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
+  COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+  COALESCE((
+    SELECT SUM(penalties.minutes)
+    FROM penalties
+    JOIN game_players offenders ON offenders.id=penalties.offender
+    JOIN games offender_games ON offender_games.id=offenders.game
+    WHERE offenders.player=players.id
+      AND offender_games.division=$1
+      AND game_in_season(offender_games.id, $2)
+  ), 0) AS pim,
+  (SELECT COALESCE(SUM(shot_attempts), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND division_id=$1 AND game_in_season(game_id, $2)) AS shot_attempts,
+  (SELECT COALESCE(SUM(shots_on_goal), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND division_id=$1 AND game_in_season(game_id, $2)) AS shots_on_goal,
+  (SELECT COALESCE(SUM(missed_shots), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND division_id=$1 AND game_in_season(game_id, $2)) AS missed_shots,
+  (SELECT COALESCE(SUM(shots_blocked), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND division_id=$1 AND game_in_season(game_id, $2)) AS shots_blocked,
+  (SELECT COALESCE(SUM(blocks), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND division_id=$1 AND game_in_season(game_id, $2)) AS blocks,
+  players.first_names,
+  players.last_name
+FROM game_players
+JOIN players ON players.id=game_players.player
+JOIN games ON games.id=game_players.game
+LEFT JOIN shots
+  ON shots.goal=true
+ AND (shots.shooter=game_players.id
+  OR shots.assistant=game_players.id
+  OR shots.assistant_second=game_players.id)
+WHERE games.division=$1
+  AND game_in_season(games.id, $2)
+GROUP BY players.id
+ORDER BY
+  points DESC,
+  goals DESC;
+"#;
+    sqlx::query_as::<_, PlayerStats>(query)
+        .bind(division_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+}
 pub async fn game_play_by_play(
     pool: &PgPool,
     game_id: i32,
@@ -265,7 +574,8 @@ SELECT
   teams.id AS team_id,
   shots.period_time AS time_remaining,
   period_types.id AS period_id,
-  period_types.short_name AS period_short_name
+  period_types.short_name AS period_short_name,
+  goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net) AS strength
 FROM shots
 JOIN game_players ON game_players.id=shots.shooter
 JOIN players ON players.id=game_players.player
@@ -288,7 +598,242 @@ ORDER BY
     .await
 }
 
+/// Returns the details of a single shot; see [`game_play_by_play`].
+pub async fn shot_details(
+    pool: &PgPool,
+    shot_id: i32,
+    lang: i32,
+) -> Result<ShotDetails, sqlx::Error> {
+    sqlx::query_as::<_, ShotDetails>(
+        r#"
+SELECT 
+  shots.shooter AS player_id,
+  shots.assistant AS first_assist_id,
+  shots.assistant_second AS second_assist_id,
+  shots.goal AS is_goal,
+  players.first_names AS player_first_names,
+  players.last_name AS player_last_name,
+  p_assistant.first_names AS first_assist_first_names,
+  p_assistant.last_name AS first_assist_last_name,
+  p_assistant_second.first_names AS second_assist_first_names,
+  p_assistant_second.last_name AS second_assist_last_name,
+  game_players.player_number AS player_number,
+  gp_assistant.player_number AS first_assist_number,
+  gp_assistant_second.player_number AS second_assist_number,
+  team_name(teams.id, $2) AS team_name,
+  teams.id AS team_id,
+  shots.period_time AS time_remaining,
+  period_types.id AS period_id,
+  period_types.short_name AS period_short_name,
+  goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net) AS strength
+FROM shots
+JOIN game_players ON game_players.id=shots.shooter
+JOIN players ON players.id=game_players.player
+JOIN teams ON teams.id=game_players.team
+LEFT JOIN game_players gp_assistant ON gp_assistant.id=shots.assistant
+LEFT JOIN players p_assistant ON p_assistant.id=gp_assistant.player
+LEFT JOIN game_players gp_assistant_second ON gp_assistant_second.id=shots.assistant_second
+LEFT JOIN players p_assistant_second ON p_assistant_second.id=gp_assistant_second.player
+JOIN periods ON shots.period=periods.id
+JOIN period_types ON periods.period_type=period_types.id
+WHERE shots.id=$1;
+"#,
+    )
+    .bind(shot_id)
+    .bind(lang)
+    .fetch_one(pool)
+    .await
+}
+/// Returns what is needed to announce a goal; see `notifications::announcement`.
+pub async fn goal_notification(
+    pool: &PgPool,
+    shot_id: i32,
+    lang: i32,
+) -> Result<Notification, sqlx::Error> {
+    let query = r#"
+SELECT
+  periods.game AS game_id,
+  team_name(teams.id, $2) AS scorer_team_name,
+  players.first_names AS scorer_first_names,
+  players.last_name AS scorer_last_name,
+  positions.name AS position,
+  positions.short_name AS position_short_name,
+  game_players.player_number AS scorer_number,
+  shots.period_time AS period_time_left,
+  period_types.name AS period_name,
+  period_types.short_name AS period_short_name
+FROM
+  shots
+JOIN game_players ON game_players.id=shots.shooter
+JOIN players ON players.id=game_players.player
+JOIN teams ON teams.id=game_players.team
+JOIN periods ON periods.id=shots.period
+JOIN period_types ON period_types.id=periods.period_type
+JOIN positions ON positions.id=game_players.position
+WHERE shots.id=$1
+  AND shots.goal=true;
+"#;
+    sqlx::query_as::<_, Notification>(query)
+        .bind(shot_id)
+        .bind(lang)
+        .fetch_one(pool)
+        .await
+}
+pub async fn game_roster(
+    pool: &PgPool,
+    game_id: i32,
+    lang: i32,
+) -> Result<Vec<RosterPlayer>, sqlx::Error> {
+    let query = r#"
+SELECT
+  game_players.id,
+  game_players.player AS player_id,
+  game_players.player_number,
+  players.first_names,
+  players.last_name,
+  game_players.team AS team_id,
+  team_name(game_players.team, $2) AS team_name,
+  positions.short_name AS position_short_name,
+  player_classification(players.id, games.start_at::DATE) AS classification
+FROM game_players
+JOIN players ON players.id=game_players.player
+JOIN positions ON positions.id=game_players.position
+JOIN games ON games.id=game_players.game
+WHERE game_players.game=$1
+ORDER BY
+  game_players.team ASC,
+  game_players.player_number ASC;
+"#;
+    sqlx::query_as::<_, RosterPlayer>(query)
+        .bind(game_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+pub async fn game_penalties(
+    pool: &PgPool,
+    game_id: i32,
+    lang: i32,
+) -> Result<Vec<PenaltyDetails>, sqlx::Error> {
+    let query = r#"
+SELECT
+  penalties.id,
+  offender_players.id AS player_id,
+  offender_players.first_names AS player_first_names,
+  offender_players.last_name AS player_last_name,
+  offenders.player_number AS player_number,
+  offenders.team AS team_id,
+  team_name(offenders.team, $2) AS team_name,
+  served_players.first_names AS served_by_first_names,
+  served_players.last_name AS served_by_last_name,
+  served.player_number AS served_by_number,
+  infractions.short_name AS infraction_short_name,
+  infraction_name(infractions.id, $2) AS infraction_name,
+  penalties.minutes,
+  periods.id AS period_id,
+  period_types.short_name AS period_short_name,
+  penalties.period_time
+FROM penalties
+JOIN game_players offenders ON offenders.id=penalties.offender
+JOIN players offender_players ON offender_players.id=offenders.player
+JOIN game_players served ON served.id=penalties.served_by
+JOIN players served_players ON served_players.id=served.player
+JOIN infractions ON infractions.id=penalties.infraction
+JOIN periods ON periods.id=penalties.period
+JOIN period_types ON period_types.id=periods.period_type
+WHERE periods.game=$1
+ORDER BY
+  period_types.id ASC,
+  penalties.period_time ASC;
+"#;
+    sqlx::query_as::<_, PenaltyDetails>(query)
+        .bind(game_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+/// Every shootout attempt of the game, in order.
+pub async fn game_shootout(
+    pool: &PgPool,
+    game_id: i32,
+    lang: i32,
+) -> Result<Vec<ShootoutAttemptDetails>, sqlx::Error> {
+    let query = r#"
+SELECT
+  shootout_attempts.id,
+  shootout_attempts.attempt_number,
+  shootout_attempts.goal,
+  shooter_players.id AS player_id,
+  shooter_players.first_names AS player_first_names,
+  shooter_players.last_name AS player_last_name,
+  shooters.player_number AS player_number,
+  shooters.team AS team_id,
+  team_name(shooters.team, $2) AS team_name,
+  goalie_players.first_names AS goalie_first_names,
+  goalie_players.last_name AS goalie_last_name,
+  goalies.player_number AS goalie_number
+FROM shootout_attempts
+JOIN game_players shooters ON shooters.id=shootout_attempts.shooter
+JOIN players shooter_players ON shooter_players.id=shooters.player
+JOIN game_players goalies ON goalies.id=shootout_attempts.goalie
+JOIN players goalie_players ON goalie_players.id=goalies.player
+WHERE shootout_attempts.game=$1
+ORDER BY shootout_attempts.attempt_number ASC;
+"#;
+    sqlx::query_as::<_, ShootoutAttemptDetails>(query)
+        .bind(game_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn game_periods(pool: &PgPool, game_id: i32) -> Result<Vec<PeriodDetails>, sqlx::Error> {
+    let query = r#"
+SELECT
+  periods.id,
+  periods.period_type,
+  periods.period_length,
+  period_types.short_name,
+  period_types.kind
+FROM periods
+JOIN period_types ON period_types.id=periods.period_type
+WHERE periods.game=$1
+ORDER BY periods.period_type ASC;
+"#;
+    sqlx::query_as::<_, PeriodDetails>(query)
+        .bind(game_id)
+        .fetch_all(pool)
+        .await
+}
+
 impl Game {
+    pub async fn roster(&self, pool: &PgPool, lang: i32) -> Result<Vec<RosterPlayer>, sqlx::Error> {
+        game_roster(pool, self.id, lang).await
+    }
+    pub async fn shootout(
+        &self,
+        pool: &PgPool,
+        lang: i32,
+    ) -> Result<Vec<ShootoutAttemptDetails>, sqlx::Error> {
+        game_shootout(pool, self.id, lang).await
+    }
+    /// Whether both teams scored as many goals, not counting the shootout.
+    pub async fn is_tied(&self, pool: &PgPool) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar("SELECT goals(id, team_home)=goals(id, team_away) FROM games WHERE id=$1;")
+            .bind(self.id)
+            .fetch_one(pool)
+            .await
+    }
+    pub async fn penalties(
+        &self,
+        pool: &PgPool,
+        lang: i32,
+    ) -> Result<Vec<PenaltyDetails>, sqlx::Error> {
+        game_penalties(pool, self.id, lang).await
+    }
+    pub async fn periods(&self, pool: &PgPool) -> Result<Vec<PeriodDetails>, sqlx::Error> {
+        game_periods(pool, self.id).await
+    }
     pub async fn score(&self, pool: &PgPool, lang: i32) -> Result<Vec<TeamStats>, sqlx::Error> {
         game_score(pool, self.id, lang).await
     }
@@ -321,9 +866,11 @@ impl Game {
     }
 }
 
+/// The IIHF record of every team over all games of a division, or of one of its seasons.
 pub async fn division_iihf_stats(
     pool: &PgPool,
     division_id: i32,
+    season_id: Option<i32>,
     lang: i32,
 ) -> Result<Vec<IihfStatsI64>, sqlx::Error> {
     sqlx::query_as!(
@@ -337,28 +884,470 @@ SELECT
   SUM(ot_losses) AS "ot_losses!",
   SUM(ties) AS "ties!",
   team_name(team_id, $2) AS team_name,
-  team_id AS "team_id!"
+  team_id AS "team_id!",
+  (SELECT COUNT(shots.id)
+    FROM shots
+    JOIN game_players shooters ON shooters.id=shots.shooter
+    JOIN games ON games.id=shooters.game
+    WHERE games.division=$1
+      AND game_in_season(games.id, $3)
+      AND shots.goal=true
+      AND shooters.team=team_id
+      AND goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net)='PP'
+  ) AS "pp_goals!",
+  (SELECT COUNT(shots.id)
+    FROM shots
+    JOIN game_players shooters ON shooters.id=shots.shooter
+    JOIN games ON games.id=shooters.game
+    WHERE games.division=$1
+      AND game_in_season(games.id, $3)
+      AND shots.goal=true
+      AND shooters.team<>team_id
+      AND (games.team_home=team_id OR games.team_away=team_id)
+      AND goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net)='PP'
+  ) AS "pp_goals_against!",
+  -- misconducts do not leave a team short-handed
+  (SELECT COUNT(penalties.id)
+    FROM penalties
+    JOIN game_players offenders ON offenders.id=penalties.offender
+    JOIN games ON games.id=offenders.game
+    WHERE games.division=$1
+      AND game_in_season(games.id, $3)
+      AND offenders.team<>team_id
+      AND (games.team_home=team_id OR games.team_away=team_id)
+      AND penalties.minutes NOT IN (10, 20)
+  ) AS "pp_opportunities!",
+  (SELECT COUNT(penalties.id)
+    FROM penalties
+    JOIN game_players offenders ON offenders.id=penalties.offender
+    JOIN games ON games.id=offenders.game
+    WHERE games.division=$1
+      AND game_in_season(games.id, $3)
+      AND offenders.team=team_id
+      AND penalties.minutes NOT IN (10, 20)
+  ) AS "times_short_handed!"
 FROM team_points_view
 WHERE division_id=$1
-GROUP BY team_id;
+  AND game_in_season(game_id, $3)
+GROUP BY team_id
+ORDER BY SUM(points) DESC, team_name;
 		"#,
-    division_id, lang
+    division_id, lang, season_id
     )
     .fetch_all(pool)
     .await
 }
 
+/// The result of every game in the division, or in one of its seasons, for both of its teams.
+pub async fn division_game_results(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+) -> Result<Vec<TeamGameResult>, sqlx::Error> {
+    let query = r#"
+SELECT
+  team_points_view.game_id,
+  team_points_view.team_id,
+  opponents.id AS opponent_id,
+  goals(team_points_view.game_id, team_points_view.team_id)::BIGINT AS goals_for,
+  goals(team_points_view.game_id, opponents.id)::BIGINT AS goals_against,
+  team_points_view.points::BIGINT AS points
+FROM team_points_view
+JOIN games ON games.id=team_points_view.game_id
+JOIN teams opponents
+  ON (opponents.id=games.team_home OR opponents.id=games.team_away)
+ AND opponents.id<>team_points_view.team_id
+WHERE team_points_view.division_id=$1
+  AND game_in_season(team_points_view.game_id, $2)
+ORDER BY team_points_view.game_id, team_points_view.team_id;
+"#;
+    sqlx::query_as::<_, TeamGameResult>(query)
+        .bind(division_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// The standings of the division, ranked with the IIHF tiebreakers; see [`crate::standings`].
+pub async fn division_standings(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+    lang: i32,
+) -> Result<Vec<Standing>, sqlx::Error> {
+    let teams = division_iihf_stats(pool, division_id, season_id, lang).await?;
+    let results = division_game_results(pool, division_id, season_id).await?;
+    Ok(standings::rank(teams, &results))
+}
+
+/// Sums up `goalie_stats_view` per goalie; every filter which is `Some` must match.
+async fn goalie_stats(
+    pool: &PgPool,
+    game_id: Option<i32>,
+    division_id: Option<i32>,
+    league_id: Option<i32>,
+    player_id: Option<i32>,
+    season_id: Option<i32>,
+) -> Result<Vec<GoalieStats>, sqlx::Error> {
+    let query = r#"
+SELECT
+  players.id AS player_id,
+  players.first_names,
+  players.last_name,
+  COUNT(DISTINCT goalie_stats_view.game_id) AS games_played,
+  SUM(goalie_stats_view.shots_against)::BIGINT AS shots_against,
+  SUM(goalie_stats_view.saves)::BIGINT AS saves,
+  SUM(goalie_stats_view.goals_against)::BIGINT AS goals_against,
+  COALESCE(SUM(goalie_stats_view.seconds_played), 0)::BIGINT AS seconds_played,
+  SUM(goalie_stats_view.shutouts)::BIGINT AS shutouts
+FROM goalie_stats_view
+JOIN players ON players.id=goalie_stats_view.player_id
+WHERE ($1::INT IS NULL OR goalie_stats_view.game_id=$1)
+  AND ($2::INT IS NULL OR goalie_stats_view.division_id=$2)
+  AND ($3::INT IS NULL OR goalie_stats_view.league_id=$3)
+  AND ($4::INT IS NULL OR goalie_stats_view.player_id=$4)
+  AND game_in_season(goalie_stats_view.game_id, $5)
+GROUP BY players.id
+ORDER BY
+  SUM(goalie_stats_view.saves)::FLOAT / NULLIF(SUM(goalie_stats_view.shots_against), 0) DESC NULLS LAST,
+  players.last_name;
+"#;
+    sqlx::query_as::<_, GoalieStats>(query)
+        .bind(game_id)
+        .bind(division_id)
+        .bind(league_id)
+        .bind(player_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn game_goalie_stats(pool: &PgPool, game_id: i32) -> Result<Vec<GoalieStats>, sqlx::Error> {
+    goalie_stats(pool, Some(game_id), None, None, None, None).await
+}
+
+pub async fn division_goalie_stats(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+) -> Result<Vec<GoalieStats>, sqlx::Error> {
+    goalie_stats(pool, None, Some(division_id), None, None, season_id).await
+}
+
+pub async fn league_goalie_stats(
+    pool: &PgPool,
+    league_id: i32,
+    season_id: Option<i32>,
+) -> Result<Vec<GoalieStats>, sqlx::Error> {
+    goalie_stats(pool, None, None, Some(league_id), None, season_id).await
+}
+
+impl Game {
+    pub async fn goalie_stats(&self, pool: &PgPool) -> Result<Vec<GoalieStats>, sqlx::Error> {
+        game_goalie_stats(pool, self.id).await
+    }
+}
+
 impl Division {
+    pub async fn leaders(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        min_games: i64,
+    ) -> Result<Vec<LeaderStats>, sqlx::Error> {
+        division_leaders(pool, self.id, season_id, min_games).await
+    }
     pub async fn iihf_stats(
         &self,
         pool: &PgPool,
+        season_id: Option<i32>,
+        lang: i32,
+    ) -> Result<Vec<IihfStatsI64>, sqlx::Error> {
+        division_iihf_stats(pool, self.id, season_id, lang).await
+    }
+    pub async fn standings(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        lang: i32,
+    ) -> Result<Vec<Standing>, sqlx::Error> {
+        division_standings(pool, self.id, season_id, lang).await
+    }
+    pub async fn goalie_stats(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+    ) -> Result<Vec<GoalieStats>, sqlx::Error> {
+        division_goalie_stats(pool, self.id, season_id).await
+    }
+    pub async fn team_stats(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        lang: i32,
+    ) -> Result<Vec<TeamStats>, sqlx::Error> {
+        division_team_stats(pool, self.id, season_id, lang).await
+    }
+    pub async fn player_stats(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+    ) -> Result<Vec<PlayerStats>, sqlx::Error> {
+        division_player_stats(pool, self.id, season_id).await
+    }
+}
+
+/// Every player dressed for a team in a season, or in any season if `season_id` is `None`.
+pub async fn team_roster(
+    pool: &PgPool,
+    team_id: i32,
+    season_id: Option<i32>,
+) -> Result<Vec<TeamRosterPlayer>, sqlx::Error> {
+    let query = r#"
+SELECT
+  players.id AS player_id,
+  players.first_names,
+  players.last_name,
+  latest.player_number,
+  latest.position_short_name,
+  latest.classification,
+  COUNT(game_players.id) AS games_played
+FROM game_players
+JOIN players ON players.id=game_players.player
+JOIN LATERAL (
+  SELECT
+    latest_players.player_number,
+    positions.short_name AS position_short_name,
+    player_classification(players.id, latest_games.start_at::DATE) AS classification
+  FROM game_players latest_players
+  JOIN games latest_games ON latest_games.id=latest_players.game
+  JOIN positions ON positions.id=latest_players.position
+  WHERE latest_players.player=players.id
+    AND latest_players.team=$1
+    AND game_in_season(latest_games.id, $2)
+  ORDER BY latest_games.start_at DESC
+  LIMIT 1
+) latest ON true
+WHERE game_players.team=$1
+  AND game_in_season(game_players.game, $2)
+GROUP BY
+  players.id,
+  latest.player_number,
+  latest.position_short_name,
+  latest.classification
+ORDER BY
+  latest.player_number ASC,
+  players.last_name ASC;
+"#;
+    sqlx::query_as::<_, TeamRosterPlayer>(query)
+        .bind(team_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Every game of a team in a season, played or not, in the order they start.
+pub async fn team_schedule(
+    pool: &PgPool,
+    team_id: i32,
+    season_id: Option<i32>,
+    lang: i32,
+) -> Result<Vec<TeamGame>, sqlx::Error> {
+    let query = r#"
+SELECT
+  games.id AS game_id,
+  game_name(games.id, $3) AS name,
+  games.start_at,
+  games.team_home=$1 AS home,
+  opponents.id AS opponent_id,
+  team_name(opponents.id, $3) AS opponent_name,
+  EXISTS (SELECT periods.id FROM periods WHERE periods.game=games.id) AS played,
+  goals(games.id, $1)::BIGINT AS goals_for,
+  goals(games.id, opponents.id)::BIGINT AS goals_against,
+  game_outcome(games.id, $1) AS outcome
+FROM games
+JOIN teams opponents
+  ON opponents.id=(CASE WHEN games.team_home=$1 THEN games.team_away ELSE games.team_home END)
+WHERE (games.team_home=$1 OR games.team_away=$1)
+  AND game_in_season(games.id, $2)
+ORDER BY games.start_at ASC;
+"#;
+    sqlx::query_as::<_, TeamGame>(query)
+        .bind(team_id)
+        .bind(season_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+
+/// Shot totals of a team over a season, or over every season if `season_id` is `None`; see [`division_team_stats`].
+pub async fn team_totals(
+    pool: &PgPool,
+    team_id: i32,
+    season_id: Option<i32>,
+    lang: i32,
+) -> Result<TeamStats, sqlx::Error> {
+    let query = r#"
+SELECT
+  COUNT(CASE WHEN shots.goal = true THEN shots.id END) AS goals,
+  COUNT(shots.id) AS shots,
+  COUNT(CASE WHEN shots.on_net = true THEN shots.id END) AS shots_on_goal,
+  COUNT(CASE WHEN shots.on_net = false AND shots.blocker IS NULL THEN shots.id END) AS missed_shots,
+  COUNT(CASE WHEN shots.blocker IS NOT NULL THEN shots.id END) AS shots_blocked,
+  (SELECT COUNT(blocked.id)
+    FROM shots blocked
+    JOIN game_players blockers ON blockers.id=blocked.blocker
+    WHERE blockers.team=$1
+      AND game_in_season(blockers.game, $2)
+  ) AS blocks,
+  team_name($1, $3) AS name
+FROM shots
+JOIN game_players ON game_players.id=shots.shooter
+WHERE game_players.team=$1
+  AND game_in_season(game_players.game, $2);
+"#;
+    sqlx::query_as::<_, TeamStats>(query)
+        .bind(team_id)
+        .bind(season_id)
+        .bind(lang)
+        .fetch_one(pool)
+        .await
+}
+
+/// Every game a player was dressed for, latest first.
+pub async fn player_game_log(
+    pool: &PgPool,
+    player_id: i32,
+    lang: i32,
+) -> Result<Vec<PlayerGame>, sqlx::Error> {
+    let query = r#"
+SELECT
+  games.id AS game_id,
+  game_name(games.id, $2) AS game_name,
+  games.start_at,
+  game_players.team AS team_id,
+  team_name(game_players.team, $2) AS team_name,
+  opponents.id AS opponent_id,
+  team_name(opponents.id, $2) AS opponent_name,
+  game_players.player_number,
+  positions.short_name AS position_short_name,
+  COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
+  COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+  COUNT(shots.id) AS points,
+  (SELECT shot_attempts FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS shot_attempts,
+  (SELECT shots_on_goal FROM player_shot_stats_view WHERE game_player_id=game_players.id) AS shots_on_goal
+FROM game_players
+JOIN games ON games.id=game_players.game
+JOIN positions ON positions.id=game_players.position
+JOIN teams opponents
+  ON opponents.id=(CASE WHEN games.team_home=game_players.team THEN games.team_away ELSE games.team_home END)
+LEFT JOIN shots
+  ON shots.goal=true
+ AND (shots.shooter=game_players.id
+  OR shots.assistant=game_players.id
+  OR shots.assistant_second=game_players.id)
+WHERE game_players.player=$1
+GROUP BY
+  game_players.id,
+  games.id,
+  opponents.id,
+  positions.short_name
+ORDER BY games.start_at DESC;
+"#;
+    sqlx::query_as::<_, PlayerGame>(query)
+        .bind(player_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+
+/// The stats of a player in every division they played in, latest league first.
+pub async fn player_stats_by_division(
+    pool: &PgPool,
+    player_id: i32,
+    lang: i32,
+) -> Result<Vec<PlayerDivisionStats>, sqlx::Error> {
+    let query = r#"
+SELECT
+  divisions.league AS league_id,
+  league_name(divisions.league, $2) AS league_name,
+  divisions.id AS division_id,
+  division_name(divisions.id, $2) AS division_name,
+  COUNT(DISTINCT game_players.id) AS games_played,
+  COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
+  COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+  COUNT(shots.id) AS points,
+  COALESCE((
+    SELECT SUM(penalties.minutes)
+    FROM penalties
+    JOIN game_players offenders ON offenders.id=penalties.offender
+    JOIN games offender_games ON offender_games.id=offenders.game
+    WHERE offenders.player=$1
+      AND offender_games.division=divisions.id
+  ), 0) AS pim,
+  (SELECT COALESCE(SUM(shot_attempts), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=$1 AND division_id=divisions.id) AS shot_attempts,
+  (SELECT COALESCE(SUM(shots_on_goal), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=$1 AND division_id=divisions.id) AS shots_on_goal
+FROM game_players
+JOIN games ON games.id=game_players.game
+JOIN divisions ON divisions.id=games.division
+LEFT JOIN shots
+  ON shots.goal=true
+ AND (shots.shooter=game_players.id
+  OR shots.assistant=game_players.id
+  OR shots.assistant_second=game_players.id)
+WHERE game_players.player=$1
+GROUP BY divisions.id
+ORDER BY
+  MAX(games.start_at) DESC,
+  divisions.id;
+"#;
+    sqlx::query_as::<_, PlayerDivisionStats>(query)
+        .bind(player_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await
+}
+
+impl Team {
+    pub async fn roster(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+    ) -> Result<Vec<TeamRosterPlayer>, sqlx::Error> {
+        team_roster(pool, self.id, season_id).await
+    }
+    pub async fn schedule(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        lang: i32,
+    ) -> Result<Vec<TeamGame>, sqlx::Error> {
+        team_schedule(pool, self.id, season_id, lang).await
+    }
+    pub async fn totals(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
         lang: i32,
-    ) -> Result<Vec<IihfStatsI64>, sqlx::Error> {
-        division_iihf_stats(pool, self.id, lang).await
+    ) -> Result<TeamStats, sqlx::Error> {
+        team_totals(pool, self.id, season_id, lang).await
+    }
+    /// The team's place in the standings of its division, or `None` if it has not played yet.
+    pub async fn standing(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        lang: i32,
+    ) -> Result<Option<Standing>, sqlx::Error> {
+        Ok(division_standings(pool, self.division, season_id, lang)
+            .await?
+            .into_iter()
+            .find(|standing| standing.team.team_id == self.id))
     }
 }
 
 impl Player {
+    /// The league of the player's latest game, or `None` if they have not played any.
     pub async fn latest_league(
         pool: &PgPool,
         id: i32,
@@ -366,7 +1355,7 @@ impl Player {
     ) -> Result<Option<League>, sqlx::Error> {
 				sqlx::query_as!(
 					League,
-  r#"SELECT leagues.*,team_name(teams.id, $2) AS name
+  r#"SELECT leagues.*,league_name(leagues.id, $2) AS name
   FROM players
   JOIN game_players ON game_players.player=players.id
   JOIN games ON games.id=game_players.game
@@ -404,7 +1393,8 @@ impl Player {
     teams.id AS team_id,
     shots.period_time AS time_remaining,
     period_types.id AS period_id,
-    period_types.short_name AS period_short_name
+    period_types.short_name AS period_short_name,
+    goal_strength(shots.shooter_skaters, shots.opponent_skaters, shots.empty_net) AS strength
   FROM shots
   JOIN game_players ON game_players.id=shots.shooter
   JOIN players ON players.id=game_players.player
@@ -412,10 +1402,11 @@ impl Player {
   LEFT JOIN game_players gp_assist ON gp_assist.id=shots.assistant
   LEFT JOIN players p_assist ON p_assist.id=gp_assist.player
   LEFT JOIN game_players gp_assist_second ON gp_assist_second.id=shots.assistant_second
-  LEFT JOIN players p_assist_second ON p_assist_second.id=gp_assist_second.id
+  LEFT JOIN players p_assist_second ON p_assist_second.id=gp_assist_second.player
   JOIN periods ON shots.period=periods.id
   JOIN period_types ON period_types.id=periods.period_type
   WHERE players.id=$1
+    AND shots.goal=true
   ORDER BY
     shots.created_at DESC,
     periods.period_type DESC,
@@ -428,28 +1419,54 @@ impl Player {
             .fetch_all(pool)
             .await
     }
+    /// The goalie stats of a player over all games, or `None` if they never faced a shot.
+    pub async fn lifetime_goalie_stats(
+        pool: &PgPool,
+        id: i32,
+    ) -> Result<Option<GoalieStats>, sqlx::Error> {
+        Ok(goalie_stats(pool, None, None, None, Some(id), None).await?.pop())
+    }
+    pub async fn game_log(
+        pool: &PgPool,
+        id: i32,
+        lang: i32,
+    ) -> Result<Vec<PlayerGame>, sqlx::Error> {
+        player_game_log(pool, id, lang).await
+    }
+    pub async fn stats_by_division(
+        pool: &PgPool,
+        id: i32,
+        lang: i32,
+    ) -> Result<Vec<PlayerDivisionStats>, sqlx::Error> {
+        player_stats_by_division(pool, id, lang).await
+    }
+    /// The stats of a player over all games; every stat is 0 for a player who has not played yet.
     pub async fn lifetime_stats(pool: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Error> {
         let query = r#"
   SELECT
-    COUNT(goals) AS goals,
-    COUNT(assists) AS assists,
-    COUNT(points) AS points,
+    COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
+    COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+    COUNT(shots.id) AS points,
+    COALESCE((
+      SELECT SUM(penalties.minutes)
+      FROM penalties
+      JOIN game_players offenders ON offenders.id=penalties.offender
+      WHERE offenders.player=players.id
+    ), 0) AS pim,
+    (SELECT COALESCE(SUM(shot_attempts), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id) AS shot_attempts,
+    (SELECT COALESCE(SUM(shots_on_goal), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id) AS shots_on_goal,
+    (SELECT COALESCE(SUM(missed_shots), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id) AS missed_shots,
+    (SELECT COALESCE(SUM(shots_blocked), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id) AS shots_blocked,
+    (SELECT COALESCE(SUM(blocks), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id) AS blocks,
     players.first_names AS first_names,
     players.last_name AS last_name
   FROM players
-  JOIN game_players ON game_players.player=players.id
-  LEFT JOIN shots points
-    ON (points.shooter=game_players.id
-    OR points.assistant=game_players.id
-    OR points.assistant_second=game_players.id)
-   AND points.goal=true
-  LEFT JOIN shots goals
-    ON goals.shooter=game_players.id
-   AND goals.goal=true
-  LEFT JOIN shots assists
-    ON (assists.assistant=game_players.id
-    OR assists.assistant_second=game_players.id)
-   AND assists.goal=true
+  LEFT JOIN game_players ON game_players.player=players.id
+  LEFT JOIN shots
+    ON shots.goal=true
+   AND (shots.shooter=game_players.id
+    OR shots.assistant=game_players.id
+    OR shots.assistant_second=game_players.id)
   WHERE players.id=$1
   GROUP BY players.id;
   "#;
@@ -459,77 +1476,169 @@ impl Player {
             .await
     }
 }
-async fn get_player_stats_overview(pool: PgPool) -> Result<Vec<PlayerStats>, sqlx::Error> {
+/// Sums up the scoring of every player in a league or a division; every filter which is `Some` must match.
+/// Only players dressed for at least `min_games` games are listed, by points.
+async fn player_leaders(
+    pool: &PgPool,
+    league_id: Option<i32>,
+    division_id: Option<i32>,
+    season_id: Option<i32>,
+    min_games: i64,
+) -> Result<Vec<LeaderStats>, sqlx::Error> {
     let query = r#"
 SELECT
-  COUNT(shots.id) AS points,
+  players.id AS player_id,
+  players.first_names,
+  players.last_name,
+  COUNT(DISTINCT game_players.id) AS games_played,
   COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
   COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
-  players.first_names AS first_names,
-  players.last_name AS last_name
+  COUNT(shots.id) AS points,
+  COALESCE((
+    SELECT SUM(penalties.minutes)
+    FROM penalties
+    JOIN game_players offenders ON offenders.id=penalties.offender
+    JOIN games offender_games ON offender_games.id=offenders.game
+    JOIN divisions offender_divisions ON offender_divisions.id=offender_games.division
+    WHERE offenders.player=players.id
+      AND ($1::INT IS NULL OR offender_divisions.league=$1)
+      AND ($2::INT IS NULL OR offender_games.division=$2)
+      AND game_in_season(offender_games.id, $3)
+  ), 0) AS pim,
+  (SELECT COALESCE(SUM(shot_attempts), 0)::BIGINT
+    FROM player_shot_stats_view
+    WHERE player_shot_stats_view.player_id=players.id
+      AND ($1::INT IS NULL OR player_shot_stats_view.league_id=$1)
+      AND ($2::INT IS NULL OR player_shot_stats_view.division_id=$2)
+      AND game_in_season(player_shot_stats_view.game_id, $3)
+  ) AS shot_attempts,
+  (SELECT COALESCE(SUM(shots_on_goal), 0)::BIGINT
+    FROM player_shot_stats_view
+    WHERE player_shot_stats_view.player_id=players.id
+      AND ($1::INT IS NULL OR player_shot_stats_view.league_id=$1)
+      AND ($2::INT IS NULL OR player_shot_stats_view.division_id=$2)
+      AND game_in_season(player_shot_stats_view.game_id, $3)
+  ) AS shots_on_goal
 FROM game_players
-JOIN players ON game_players.player = players.id
+JOIN players ON players.id=game_players.player
+JOIN games ON games.id=game_players.game
+JOIN divisions ON divisions.id=games.division
 LEFT JOIN shots
   ON shots.goal=true
  AND (shots.shooter=game_players.id
   OR shots.assistant=game_players.id
   OR shots.assistant_second=game_players.id)
-GROUP BY
-  game_players.id,
-  players.first_names,
-  players.last_name
+WHERE ($1::INT IS NULL OR divisions.league=$1)
+  AND ($2::INT IS NULL OR games.division=$2)
+  AND game_in_season(games.id, $3)
+GROUP BY players.id
+HAVING COUNT(DISTINCT game_players.id) >= $4
 ORDER BY
   points DESC,
-  goals DESC;
+  goals DESC,
+  players.last_name;
 "#;
-    sqlx::query_as::<_, PlayerStats>(query)
-        .fetch_all(&pool)
+    sqlx::query_as::<_, LeaderStats>(query)
+        .bind(league_id)
+        .bind(division_id)
+        .bind(season_id)
+        .bind(min_games)
+        .fetch_all(pool)
         .await
 }
 
+pub async fn league_leaders(
+    pool: &PgPool,
+    league_id: i32,
+    season_id: Option<i32>,
+    min_games: i64,
+) -> Result<Vec<LeaderStats>, sqlx::Error> {
+    player_leaders(pool, Some(league_id), None, season_id, min_games).await
+}
+
+pub async fn division_leaders(
+    pool: &PgPool,
+    division_id: i32,
+    season_id: Option<i32>,
+    min_games: i64,
+) -> Result<Vec<LeaderStats>, sqlx::Error> {
+    player_leaders(pool, None, Some(division_id), season_id, min_games).await
+}
+
 impl League {
+    pub async fn leaders(
+        &self,
+        pool: &PgPool,
+        season_id: Option<i32>,
+        min_games: i64,
+    ) -> Result<Vec<LeaderStats>, sqlx::Error> {
+        league_leaders(pool, self.id, season_id, min_games).await
+    }
+    /// The goalie stats of a player in this league, or `None` if they never faced a shot in it.
+    pub async fn player_goalie_stats(
+        pool: &PgPool,
+        player_id: i32,
+        league_id: i32,
+        season_id: Option<i32>,
+    ) -> Result<Option<GoalieStats>, sqlx::Error> {
+        Ok(goalie_stats(pool, None, None, Some(league_id), Some(player_id), season_id)
+            .await?
+            .pop())
+    }
+    /// The stats of a player in this league, or in one of its seasons if `season_id` is `Some`.
     pub async fn player_stats(
         pool: &PgPool,
         player_id: i32,
         league_id: i32,
+        season_id: Option<i32>,
     ) -> Result<PlayerStats, sqlx::Error> {
         let query = r#"
   SELECT
-    COUNT(goals.id) AS goals,
-    COUNT(assists.id) AS assists,
-    COUNT(points.id) AS points,
+    COUNT(CASE WHEN shots.shooter = game_players.id THEN shots.id END) AS goals,
+    COUNT(CASE WHEN shots.assistant = game_players.id OR shots.assistant_second = game_players.id THEN shots.id END) AS assists,
+    COUNT(shots.id) AS points,
+    COALESCE((
+      SELECT SUM(penalties.minutes)
+      FROM penalties
+      JOIN game_players offenders ON offenders.id=penalties.offender
+      JOIN games offender_games ON offender_games.id=offenders.game
+      JOIN divisions offender_divisions ON offender_divisions.id=offender_games.division
+      WHERE offenders.player=players.id
+        AND offender_divisions.league=$1
+        AND game_in_season(offender_games.id, $3)
+    ), 0) AS pim,
+    (SELECT COALESCE(SUM(shot_attempts), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND league_id=$1 AND game_in_season(game_id, $3)) AS shot_attempts,
+    (SELECT COALESCE(SUM(shots_on_goal), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND league_id=$1 AND game_in_season(game_id, $3)) AS shots_on_goal,
+    (SELECT COALESCE(SUM(missed_shots), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND league_id=$1 AND game_in_season(game_id, $3)) AS missed_shots,
+    (SELECT COALESCE(SUM(shots_blocked), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND league_id=$1 AND game_in_season(game_id, $3)) AS shots_blocked,
+    (SELECT COALESCE(SUM(blocks), 0)::BIGINT FROM player_shot_stats_view WHERE player_id=players.id AND league_id=$1 AND game_in_season(game_id, $3)) AS blocks,
     players.first_names AS first_names,
     players.last_name AS last_name
   FROM players
   JOIN game_players ON game_players.player=players.id
-  LEFT JOIN shots goals
-    ON goals.goal=true
-   AND goals.shooter=game_players.id
-  LEFT JOIN shots assists
-    ON assists.goal=true
-   AND (assists.assistant=game_players.id
-    OR assists.assistant_second=game_players.id) 
-  LEFT JOIN shots points
-    ON points.goal=true
-   AND (points.shooter=game_players.id
-    OR points.assistant=game_players.id
-    OR points.assistant_second=game_players.id)
+  LEFT JOIN shots
+    ON shots.goal=true
+   AND (shots.shooter=game_players.id
+    OR shots.assistant=game_players.id
+    OR shots.assistant_second=game_players.id)
   JOIN games ON games.id=game_players.game
   JOIN divisions ON divisions.id=games.division
   JOIN leagues ON leagues.id=divisions.league
   WHERE leagues.id=$1
     AND players.id=$2
+    AND game_in_season(games.id, $3)
   GROUP BY players.id;
   "#;
         sqlx::query_as::<_, PlayerStats>(query)
             .bind(league_id)
             .bind(player_id)
+            .bind(season_id)
             .fetch_one(pool)
             .await
     }
 }
 
-#[derive(FromRow, Deserialize, Serialize, Debug)]
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
 pub struct GoalDetails {
     pub player_id: i32,
     pub player_first_names: String,
@@ -540,6 +1649,8 @@ pub struct GoalDetails {
     pub time_remaining: i32,
     pub period_id: i32,
     pub period_short_name: String,
+    /// `EV` (even strength), `PP` (power play), `SH` (short-handed) or `EN` (empty net).
+    pub strength: String,
     pub first_assist_first_names: Option<String>,
     pub first_assist_last_name: Option<String>,
     pub first_assist_number: Option<i32>,
@@ -550,7 +1661,72 @@ pub struct GoalDetails {
     pub second_assist_number: Option<i32>,
 }
 
-#[derive(FromRow, Deserialize, Serialize, Debug)]
+/// A goalie's stats over some games; see [`game_goalie_stats`] and the like.
+/// Empty-net goals are not counted against any goalie.
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
+pub struct GoalieStats {
+    pub player_id: i32,
+    pub first_names: String,
+    pub last_name: String,
+    pub games_played: i64,
+    pub shots_against: i64,
+    pub saves: i64,
+    pub goals_against: i64,
+    /// The length of every period the goalie was in net for; see `goalie_periods_view`.
+    pub seconds_played: i64,
+    pub shutouts: i64,
+}
+impl GoalieStats {
+    /// Saves per shot against, between 0 and 1; `None` without any shot against.
+    pub fn save_percentage(&self) -> Option<f64> {
+        (self.shots_against > 0).then(|| self.saves as f64 / self.shots_against as f64)
+    }
+    /// Goals against per 60 minutes played; `None` without any time played.
+    pub fn goals_against_average(&self) -> Option<f64> {
+        (self.seconds_played > 0)
+            .then(|| self.goals_against as f64 * 3600.0 / self.seconds_played as f64)
+    }
+}
+
+/// A penalty as shown in the penalty summary; see [`game_penalties`].
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
+pub struct PenaltyDetails {
+    pub id: i32,
+    pub player_id: i32,
+    pub player_first_names: String,
+    pub player_last_name: String,
+    pub player_number: i32,
+    pub team_id: i32,
+    pub team_name: String,
+    pub served_by_first_names: String,
+    pub served_by_last_name: String,
+    pub served_by_number: i32,
+    pub infraction_short_name: String,
+    pub infraction_name: String,
+    pub minutes: i32,
+    pub period_id: i32,
+    pub period_short_name: String,
+    pub period_time: i32,
+}
+
+/// A shootout attempt as shown in the shootout summary; see [`game_shootout`].
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
+pub struct ShootoutAttemptDetails {
+    pub id: i32,
+    pub attempt_number: i32,
+    pub goal: bool,
+    pub player_id: i32,
+    pub player_first_names: String,
+    pub player_last_name: String,
+    pub player_number: i32,
+    pub team_id: i32,
+    pub team_name: String,
+    pub goalie_first_names: String,
+    pub goalie_last_name: String,
+    pub goalie_number: i32,
+}
+
+#[derive(FromRow, Deserialize, Serialize, Debug, ToSchema)]
 pub struct ShotDetails {
     pub player_id: i32,
     pub player_first_names: String,
@@ -561,6 +1737,8 @@ pub struct ShotDetails {
     pub is_goal: bool,
     pub time_remaining: i32,
     pub period_short_name: String,
+    /// `EV` (even strength), `PP` (power play), `SH` (short-handed) or `EN` (empty net).
+    pub strength: String,
     pub first_assist_first_names: Option<String>,
     pub first_assist_last_name: Option<String>,
     pub first_assist_number: Option<i32>,
@@ -573,13 +1751,18 @@ pub struct ShotDetails {
 
 #[cfg(test)]
 mod tests {
+    use crate::classification;
     use crate::languages::SupportedLanguage;
-    use crate::model::{Game, League, Player};
+    use crate::model::{ClassificationRule, Game, League, Player, PointsSystem, Team};
     use crate::views::{
-        division_iihf_stats, game_box_score, game_goals, game_iihf_points, game_iihf_stats,
-        game_play_by_play, game_score, get_player_stats_overview, Notification,
+        division_game_results, division_goalie_stats, division_iihf_stats, division_leaders,
+        division_player_stats, division_standings, division_team_stats, game_box_score,
+        game_goalie_stats, game_goals, game_iihf_points, game_iihf_stats, game_penalties,
+        game_periods, game_play_by_play, game_roster, game_score, goal_notification, league_leaders,
+        shot_details, GoalieStats, IihfStatsI64, LeaderSort,
     };
     use ormx::Table;
+    use sqlx::{Postgres, Transaction};
     use std::env;
 
     #[test]
@@ -592,6 +1775,55 @@ mod tests {
         })
     }
 
+    #[test]
+    fn check_shot_details() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let shot = shot_details(&pool, 1, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(shot.player_last_name, "Hoyem");
+            assert_eq!(shot.period_short_name, "1");
+        })
+    }
+
+    #[test]
+    fn check_game_penalties() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let penalties = game_penalties(&pool, 1, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert!(
+                penalties.iter().all(|penalty| penalty.minutes > 0),
+                "Every penalty should be at least a minute long."
+            );
+        })
+    }
+
+    #[test]
+    fn check_game_roster() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let roster = game_roster(&pool, 4, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert!(!roster.is_empty(), "Game 4 should have players dressed.");
+            assert!(
+                roster.iter().all(|player| player.team_id == 1 || player.team_id == 2),
+                "Only the home and away teams should be on the roster."
+            );
+            let rules = ClassificationRule::by_division(&pool, 1).await.unwrap();
+            assert_eq!(
+                classification::validate(&roster, &rules),
+                Vec::new(),
+                "Every player of the 2022 tournament was classified."
+            );
+            let periods = game_periods(&pool, 4).await.unwrap();
+            assert_eq!(periods.first().unwrap().short_name, "1");
+        })
+    }
+
     #[test]
     fn get_latest_stats_of_player() {
         tokio_test::block_on(async move {
@@ -600,6 +1832,11 @@ mod tests {
             let latest = Player::latest_stats(&pool, player.id, SupportedLanguage::English.into())
                 .await
                 .unwrap();
+            let lafrance = Player::latest_stats(&pool, 14, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(lafrance.len(), 2, "Only goals are listed, not every shot.");
+            assert_eq!(lafrance.get(0).unwrap().player_last_name, "Lafrance");
         })
     }
 
@@ -612,10 +1849,59 @@ mod tests {
                 .unwrap()
                 .unwrap();
             let player = Player::get(&pool, 2).await.unwrap();
-            let stats = League::player_stats(&pool, player.id, league.id)
+            let stats = League::player_stats(&pool, player.id, league.id, None)
                 .await
                 .unwrap();
             assert_eq!(stats.last_name, "Scanlon");
+            assert_eq!(stats.goals, 5);
+            assert_eq!(stats.assists, 1);
+            assert_eq!(stats.points, 6);
+            let season = League::player_stats(&pool, player.id, league.id, Some(1))
+                .await
+                .unwrap();
+            assert_eq!(season.points, stats.points, "Every game of the league was in its 2022 season.");
+        })
+    }
+
+    #[test]
+    fn check_player_game_log() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let log = Player::game_log(&pool, 2, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(log.len(), 4);
+            assert_eq!(log[0].game_id, 4, "The latest game comes first.");
+            assert_eq!(log[0].opponent_id, 2);
+            let goals: i64 = log.iter().map(|game| game.goals).sum();
+            let lifetime = Player::lifetime_stats(&pool, 2).await.unwrap();
+            assert_eq!(goals, lifetime.goals);
+            let divisions = Player::stats_by_division(&pool, 2, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(divisions.len(), 1);
+            assert_eq!(divisions[0].division_id, 1);
+            assert_eq!(divisions[0].games_played, 4);
+            assert_eq!(divisions[0].points, lifetime.points);
+        })
+    }
+
+    #[test]
+    fn check_player_without_games() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let player = Player::get(&pool, 6).await.unwrap();
+            let league = Player::latest_league(&pool, player.id, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert!(league.is_none());
+            let lifetime = Player::lifetime_stats(&pool, player.id).await.unwrap();
+            assert_eq!(lifetime.points, 0);
+            assert_eq!(lifetime.shot_attempts, 0);
+            let log = Player::game_log(&pool, player.id, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert!(log.is_empty());
         })
     }
 
@@ -669,7 +1955,7 @@ mod tests {
     fn check_division_iihf_stats() {
         tokio_test::block_on(async move {
             let pool = db_connect().await;
-            let score = division_iihf_stats(&pool, 1, SupportedLanguage::English.into())
+            let score = division_iihf_stats(&pool, 1, None, SupportedLanguage::English.into())
                 .await
                 .unwrap();
             let team_1 = score.get(0).unwrap();
@@ -695,9 +1981,158 @@ mod tests {
                 team_2.points, 4,
                 "The second-place team should have four points"
             );
+            assert_eq!(
+                team_1.power_play_percentage(),
+                None,
+                "No penalties were called in the division."
+            );
+        })
+    }
+
+    #[test]
+    fn check_division_standings() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let results = division_game_results(&pool, 1, None).await.unwrap();
+            assert_eq!(results.len(), 8, "Both teams of the four games should have a result.");
+            let standings = division_standings(&pool, 1, None, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(standings.len(), 2);
+            let first = standings.get(0).unwrap();
+            assert_eq!(first.team.team_name.as_deref(), Some("Bullseye"));
+            assert_eq!(first.rank, 1);
+            assert_eq!(first.tiebreak, None, "The teams are not tied on points.");
+            assert_eq!((first.goals_for, first.goals_against), (8, 6));
+            assert_eq!(standings.get(1).unwrap().rank, 2);
+            assert_eq!(standings.get(1).unwrap().goal_difference(), -2);
+            let season = division_standings(&pool, 1, Some(1), SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(season.len(), 2, "Every game was played in the 2022 season.");
+            let other_season = division_standings(&pool, 1, Some(0), SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert!(other_season.is_empty(), "No games were played in a season which does not exist.");
+        })
+    }
+
+    #[test]
+    fn check_team_page() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let team = Team::get(&pool, 1, SupportedLanguage::English.into())
+                .await
+                .unwrap()
+                .unwrap();
+            let roster = team.roster(&pool, None).await.unwrap();
+            let goalie = roster.iter().find(|player| player.player_id == 3).unwrap();
+            assert_eq!(goalie.position_short_name, "G");
+            assert_eq!(goalie.games_played, 4);
+            let schedule = team
+                .schedule(&pool, Some(1), SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            let results: Vec<(i32, bool, i64, i64, &str)> = schedule
+                .iter()
+                .map(|game| (game.opponent_id, game.home, game.goals_for, game.goals_against, game.outcome.as_str()))
+                .collect();
+            assert_eq!(
+                results,
+                vec![
+                    (2, true, 1, 1, "TIE"),
+                    (2, true, 1, 0, "REG_WIN"),
+                    (2, true, 2, 1, "REG_WIN"),
+                    (2, true, 4, 4, "TIE"),
+                ]
+            );
+            let totals = team
+                .totals(&pool, None, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!((totals.name.as_str(), totals.goals), ("Bullseye", 8));
+            let standing = team
+                .standing(&pool, None, SupportedLanguage::English.into())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!((standing.rank, standing.team.points), (1, 10));
+            let other_season = team.roster(&pool, Some(0)).await.unwrap();
+            assert!(other_season.is_empty(), "Nobody played in a season which does not exist.");
+        })
+    }
+
+    #[test]
+    fn check_goalie_stats() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let goalies = division_goalie_stats(&pool, 1, None).await.unwrap();
+            assert_eq!(goalies.len(), 3, "Three goalies faced shots in the division.");
+            let rego = goalies.iter().find(|goalie| goalie.last_name == "Rego").unwrap();
+            assert_eq!(rego.shutouts, 1);
+            assert_eq!(rego.saves + rego.goals_against, rego.shots_against);
+            let game = game_goalie_stats(&pool, 2).await.unwrap();
+            assert_eq!(game.len(), 3, "Two goalies shared the net for one team in game 2.");
+            assert_eq!(
+                game.iter().map(|goalie| goalie.shutouts).sum::<i64>(),
+                1,
+                "Only the home goalie kept the other team off the board."
+            );
+            assert_eq!(
+                game.iter()
+                    .filter(|goalie| goalie.player_id != 3)
+                    .map(|goalie| goalie.seconds_played)
+                    .sum::<i64>(),
+                3600,
+                "The goalies who shared the net split the whole game."
+            );
+            let game = game_goalie_stats(&pool, 3).await.unwrap();
+            let rego = game.iter().find(|goalie| goalie.last_name == "Rego").unwrap();
+            assert_eq!(rego.seconds_played, 2640, "Rego played the third period, though no shot came in it.");
+            let skater = Player::lifetime_goalie_stats(&pool, 2).await.unwrap();
+            assert!(skater.is_none(), "Skaters have no goalie stats.");
         })
     }
 
+    #[test]
+    fn check_goalie_percentages() {
+        let stats = GoalieStats {
+            player_id: 1,
+            first_names: "First".to_string(),
+            last_name: "Last".to_string(),
+            games_played: 2,
+            shots_against: 40,
+            saves: 36,
+            goals_against: 4,
+            seconds_played: 4800,
+            shutouts: 0,
+        };
+        assert_eq!(stats.save_percentage(), Some(0.9));
+        assert_eq!(stats.goals_against_average(), Some(3.0));
+    }
+
+    #[test]
+    fn check_special_teams_percentages() {
+        let mut stats = IihfStatsI64 {
+            team_name: None,
+            team_id: 1,
+            reg_wins: 0,
+            reg_losses: 0,
+            ot_wins: 0,
+            ot_losses: 0,
+            ties: 0,
+            points: 0,
+            pp_goals: 1,
+            pp_opportunities: 4,
+            pp_goals_against: 1,
+            times_short_handed: 5,
+        };
+        assert_eq!(stats.power_play_percentage(), Some(25.0));
+        assert_eq!(stats.penalty_kill_percentage(), Some(80.0));
+        stats.times_short_handed = 0;
+        assert_eq!(stats.penalty_kill_percentage(), None);
+    }
+
     #[test]
     fn check_iihf_stats() {
         tokio_test::block_on(async move {
@@ -727,6 +2162,29 @@ mod tests {
         })
     }
 
+    #[test]
+    fn check_iihf_stats_and_points_of_a_decided_game() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let stats = game_iihf_stats(&pool, 2, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(stats.len(), 2);
+            let winner = stats.iter().find(|team| team.team_id == 1).unwrap();
+            assert_eq!(winner.team_name.as_deref(), Some("Bullseye"));
+            assert_eq!((winner.reg_wins, winner.points), (1, 3));
+            let loser = stats.iter().find(|team| team.team_id == 2).unwrap();
+            assert_eq!((loser.reg_losses, loser.points), (1, 0));
+            let points = game_iihf_points(&pool, 2, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(points.get(0).unwrap().team_name, "See Cats");
+            assert_eq!(points.get(0).unwrap().points, 0);
+            assert_eq!(points.get(1).unwrap().team_name, "Bullseye");
+            assert_eq!(points.get(1).unwrap().points, 3);
+        })
+    }
+
     #[test]
     fn check_game_score() {
         tokio_test::block_on(async move {
@@ -736,17 +2194,63 @@ mod tests {
                 .unwrap();
             assert_eq!(score.get(0).unwrap().goals, 1);
             assert_eq!(score.get(1).unwrap().goals, 1);
+            for team in &score {
+                assert_eq!(
+                    team.shots,
+                    team.shots_on_goal + team.missed_shots + team.shots_blocked,
+                    "Every shot attempt is either on goal, missed or blocked."
+                );
+            }
         })
     }
 
     #[test]
-    fn check_player_overall_stats() {
+    fn check_division_shot_stats() {
         tokio_test::block_on(async move {
             let pool = db_connect().await;
-            let players_stats = get_player_stats_overview(pool).await.unwrap();
-            for player_stats in players_stats {
-                println!("{player_stats:?}");
-            }
+            let teams = division_team_stats(&pool, 1, None, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(teams.len(), 2);
+            let players = division_player_stats(&pool, 1, None).await.unwrap();
+            assert_eq!(
+                players.iter().map(|player| player.shot_attempts).sum::<i64>(),
+                teams.iter().map(|team| team.shots).sum::<i64>(),
+                "The shots of every player should add up to the shots of every team."
+            );
+            assert_eq!(
+                players.iter().map(|player| player.goals).sum::<i64>(),
+                teams.iter().map(|team| team.goals).sum::<i64>()
+            );
+        })
+    }
+
+    #[test]
+    fn check_leaders() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let leaders = league_leaders(&pool, 1, None, 0).await.unwrap();
+            let mut ids: Vec<i32> = leaders.iter().map(|leader| leader.player_id).collect();
+            ids.sort();
+            ids.dedup();
+            assert_eq!(ids.len(), leaders.len(), "Every player is listed once, not once per game.");
+            let scanlon = leaders.iter().find(|leader| leader.player_id == 2).unwrap();
+            assert_eq!(scanlon.games_played, 4);
+            assert_eq!(scanlon.goals, 5);
+            assert_eq!(scanlon.points, 6);
+            assert!(leaders.windows(2).all(|pair| pair[0].points >= pair[1].points));
+            let division = division_leaders(&pool, 1, None, 0).await.unwrap();
+            assert_eq!(division.len(), leaders.len());
+            let regulars = league_leaders(&pool, 1, None, 5).await.unwrap();
+            assert!(regulars.is_empty(), "No one played more than four games.");
+            let mut by_goals = league_leaders(&pool, 1, None, 0).await.unwrap();
+            LeaderSort::Goals.sort(&mut by_goals);
+            assert!(by_goals.windows(2).all(|pair| pair[0].goals >= pair[1].goals));
+            LeaderSort::ShootingPercentage.sort(&mut by_goals);
+            assert!(by_goals.windows(2).all(|pair| {
+                pair[0].shooting_percentage().unwrap_or(-1.0)
+                    >= pair[1].shooting_percentage().unwrap_or(-1.0)
+            }));
         })
     }
 
@@ -769,44 +2273,165 @@ mod tests {
     fn check_notification_query() {
         tokio_test::block_on(async move {
             let pool = db_connect().await;
-            let query = r#"
-SELECT
-  team_name(teams.id, $1) AS scorer_team_name,
-  players.first_names AS scorer_first_names,
-  players.last_name AS scorer_last_name,
-  positions.name AS position,
-  game_players.player_number AS scorer_number,
-  shots.period_time AS period_time_left,
-  period_types.name AS period_name
-FROM
-  shots
-JOIN game_players ON game_players.id=shots.shooter
-JOIN players ON players.id=game_players.player
-JOIN teams ON teams.id=game_players.team
-JOIN periods ON periods.id=shots.period
-JOIN period_types ON period_types.id=periods.period_type
-JOIN positions ON positions.id=game_players.position;
-"#;
-            let result = sqlx::query_as::<_, Notification>(query)
-                .bind(1)
-                .fetch_one(&pool)
-                .await
-                .unwrap();
-            let minutes = result.period_time_left / 60;
-            let seconds = result.period_time_left % 60;
-            println!(
-                "{0} {1} player #{3} {2} has scored! Time of the goal: {4}:{5} in the {6}",
-                result.scorer_team_name,
-                result.position,
-                result.scorer_last_name,
-                result.scorer_number,
-                minutes,
-                seconds,
-                result.period_name
-            );
+            let result = goal_notification(&pool, 20, SupportedLanguage::English.into())
+                .await
+                .unwrap();
+            assert_eq!(result.game_id, 1);
+            assert_eq!(result.scorer_team_name, "See Cats");
+            assert_eq!(result.scorer_last_name, "Lafrance");
+            assert_eq!(result.scorer_number, 15);
+            assert_eq!(result.position_short_name, "C");
+            assert_eq!(result.period_short_name, "3");
+            assert_eq!(result.period_time_left, 503);
         });
     }
 
+    /// A period of a fixture game: its period type, then the goals of the home and away teams.
+    type FixturePeriod = (i32, i32, i32);
+
+    /// Adds a game of the Bullseye (home) against the See Cats (away), which only `tx` can see.
+    /// `shootout` has the result of each attempt; the teams take turns, starting with the home team.
+    async fn fixture_game(
+        tx: &mut Transaction<'_, Postgres>,
+        periods: &[FixturePeriod],
+        shootout: &[bool],
+    ) -> i32 {
+        let game: i32 = sqlx::query_scalar(
+            "INSERT INTO games (division, team_home, team_away, start_at, end_at) VALUES (1, 1, 2, now(), now()) RETURNING id;",
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .unwrap();
+        // a skater and a goalie on each team
+        let mut players = Vec::new();
+        for (team, player, position) in [(1, 1, 1), (1, 3, 5), (2, 14, 1), (2, 12, 5)] {
+            let game_player: i32 = sqlx::query_scalar(
+                "INSERT INTO game_players (team, game, player, position, player_number) VALUES ($1, $2, $3, $4, $3) RETURNING id;",
+            )
+            .bind(team)
+            .bind(game)
+            .bind(player)
+            .bind(position)
+            .fetch_one(&mut *tx)
+            .await
+            .unwrap();
+            players.push(game_player);
+        }
+        let home = (players[0], players[3]);
+        let away = (players[2], players[1]);
+        for &(period_type, home_goals, away_goals) in periods {
+            let period: i32 = sqlx::query_scalar(
+                "INSERT INTO periods (period_type, period_length, game) VALUES ($1, 1200, $2) RETURNING id;",
+            )
+            .bind(period_type)
+            .bind(game)
+            .fetch_one(&mut *tx)
+            .await
+            .unwrap();
+            for ((shooter, goalie), goals) in [(home, home_goals), (away, away_goals)] {
+                for _ in 0..goals {
+                    sqlx::query("INSERT INTO shots (shooter, goalie, period, period_time, on_net, goal) VALUES ($1, $2, $3, 600, true, true);")
+                        .bind(shooter)
+                        .bind(goalie)
+                        .bind(period)
+                        .execute(&mut *tx)
+                        .await
+                        .unwrap();
+                }
+            }
+        }
+        for (attempt_number, &goal) in (1..).zip(shootout) {
+            let (shooter, goalie) = if attempt_number % 2 == 1 { home } else { away };
+            sqlx::query("INSERT INTO shootout_attempts (game, shooter, goalie, attempt_number, goal) VALUES ($1, $2, $3, $4, $5);")
+                .bind(game)
+                .bind(shooter)
+                .bind(goalie)
+                .bind(attempt_number)
+                .bind(goal)
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+        }
+        game
+    }
+
+    /// The points an outcome is worth in a points system; shootouts count as overtime.
+    fn outcome_points(points_system: &PointsSystem, outcome: &str) -> i32 {
+        match outcome {
+            "REG_WIN" => points_system.reg_win,
+            "OT_WIN" | "SO_WIN" => points_system.ot_win,
+            "OT_LOSS" | "SO_LOSS" => points_system.ot_loss,
+            "REG_LOSS" => points_system.reg_loss,
+            "TIE" => points_system.tie,
+            _ => panic!("Unknown outcome {outcome}."),
+        }
+    }
+
+    #[test]
+    fn check_game_outcomes() {
+        // the name of the fixture, its periods and shootout, then the outcome for the home and away teams
+        let cases: [(&str, &[FixturePeriod], &[bool], &str, &str); 9] = [
+            ("regulation win", &[(1, 1, 0), (2, 0, 1), (3, 2, 0)], &[], "REG_WIN", "REG_LOSS"),
+            ("regulation loss", &[(1, 0, 1), (2, 0, 0), (3, 0, 0)], &[], "REG_LOSS", "REG_WIN"),
+            // the overtime period was added before the third ended, but not needed
+            ("regulation win with an unused overtime", &[(1, 2, 0), (2, 0, 0), (3, 0, 1), (4, 0, 0)], &[], "REG_WIN", "REG_LOSS"),
+            ("regulation win with missing periods", &[(3, 1, 0)], &[], "REG_WIN", "REG_LOSS"),
+            ("overtime loss", &[(1, 1, 1), (2, 0, 0), (3, 0, 0), (4, 0, 1)], &[], "OT_LOSS", "OT_WIN"),
+            ("second overtime win", &[(1, 0, 0), (2, 0, 0), (3, 0, 0), (4, 0, 0), (6, 1, 0)], &[], "OT_WIN", "OT_LOSS"),
+            ("shootout win", &[(1, 1, 1), (2, 0, 0), (3, 0, 0), (4, 0, 0)], &[true, false, false, false, true, false], "SO_WIN", "SO_LOSS"),
+            ("shootout loss", &[(1, 1, 1), (2, 0, 0), (3, 0, 0), (4, 0, 0)], &[false, true, false, true], "SO_LOSS", "SO_WIN"),
+            ("tie", &[(1, 2, 2), (2, 0, 0), (3, 0, 0)], &[], "TIE", "TIE"),
+        ];
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            // the fixture games are played in division 1
+            let points_system = PointsSystem::for_division(&pool, 1).await.unwrap();
+            for (name, periods, shootout, home_outcome, away_outcome) in cases {
+                // nothing is committed, so the fixture games never show up elsewhere
+                let mut tx = pool.begin().await.unwrap();
+                let game = fixture_game(&mut tx, periods, shootout).await;
+                for (team, outcome) in [(1, home_outcome), (2, away_outcome)] {
+                    let result: (String, i32) = sqlx::query_as("SELECT game_outcome($1, $2), iihf_points($1, $2);")
+                        .bind(game)
+                        .bind(team)
+                        .fetch_one(&mut tx)
+                        .await
+                        .unwrap();
+                    assert_eq!(
+                        result,
+                        (outcome.to_string(), outcome_points(&points_system, outcome)),
+                        "Wrong result of team {team} for a {name}."
+                    );
+                }
+                tx.rollback().await.unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn check_shootout_is_not_counted_as_goals() {
+        tokio_test::block_on(async move {
+            let pool = db_connect().await;
+            let mut tx = pool.begin().await.unwrap();
+            let game = fixture_game(&mut tx, &[(1, 1, 1), (4, 0, 0)], &[false, true]).await;
+            let goals: (i32, i32) = sqlx::query_as("SELECT goals($1, 1), goals($1, 2);")
+                .bind(game)
+                .fetch_one(&mut tx)
+                .await
+                .unwrap();
+            assert_eq!(goals, (1, 1), "Shootout goals only decide the winner.");
+            let away: (String, i32, i32) = sqlx::query_as(
+                "SELECT game_outcome($1, 2), ot_wins, points FROM team_points_view WHERE game_id=$1 AND team_id=2;",
+            )
+            .bind(game)
+            .fetch_one(&mut tx)
+            .await
+            .unwrap();
+            assert_eq!(away, ("SO_WIN".to_string(), 1, 2), "A shootout win counts as an overtime win.");
+            tx.rollback().await.unwrap();
+        })
+    }
+
     /// A simple function to connect to the database.
     async fn db_connect() -> sqlx::PgPool {
         let db_url = env::var("DATABASE_URL")
@@ -2,6 +2,14 @@ root_url = /:lang/
 root_url_tmpl = /{ $lang }/
 login_url = /:lang/login/
 login_url_tmpl = /{ $lang }/login/
+logout_url = /:lang/logout/
+logout_url_tmpl = /{ $lang }/logout/
+admin_url = /:lang/admin/
+admin_url_tmpl = /{ $lang }/admin/
+new_game_url = /:lang/admin/games/new/
+new_game_url_tmpl = /{ $lang }/admin/games/new/
+edit_game_url = /:lang/admin/games/:id/
+edit_game_url_tmpl = /{ $lang }/admin/games/{ $id }/
 player_url = /:lang/player/:id/
 player_url_tmpl = /{ $lang }/player/{ $id }/
 game_url = /:lang/game/:id/
@@ -10,6 +18,29 @@ league_url = /:lang/league/:id/
 league_url_tmpl = /{ $lang }/league/{ $id }/
 division_url = /:lang/division/:id/
 division_url_tmpl = /{ $lang }/division/{ $id }/
+season_archive_url = /:lang/league/:id/seasons/
+season_archive_url_tmpl = /{ $lang }/league/{ $id }/seasons/
+league_leaders_url = /:lang/league/:id/leaders/
+league_leaders_url_tmpl = /{ $lang }/league/{ $id }/leaders/
+division_leaders_url = /:lang/division/:id/leaders/
+division_leaders_url_tmpl = /{ $lang }/division/{ $id }/leaders/
+team_url = /:lang/team/:id/
+team_url_tmpl = /{ $lang }/team/{ $id }/
+announcements_url = /:lang/announcements/
+live_url = /:lang/game/:id/live/
+live_url_tmpl = /{ $lang }/game/{ $id }/live/
+scorekeeper_url = /:lang/game/:id/scorekeeper/
+scorekeeper_url_tmpl = /{ $lang }/game/{ $id }/scorekeeper/
+scorekeeper_penalty_url = /:lang/game/:id/scorekeeper/penalty/
+scorekeeper_penalty_url_tmpl = /{ $lang }/game/{ $id }/scorekeeper/penalty/
+scorekeeper_shootout_url = /:lang/game/:id/scorekeeper/shootout/
+scorekeeper_shootout_url_tmpl = /{ $lang }/game/{ $id }/scorekeeper/shootout/
+lineup_url = /:lang/game/:id/lineup/:team/
+lineup_url_tmpl = /{ $lang }/game/{ $id }/lineup/{ $team }/
+lineup_remove_url = /:lang/game/:id/lineup/:team/remove/
+lineup_remove_url_tmpl = /{ $lang }/game/{ $id }/lineup/{ $team }/remove/
+lineup_copy_url = /:lang/game/:id/lineup/:team/copy/
+lineup_copy_url_tmpl = /{ $lang }/game/{ $id }/lineup/{ $team }/copy/
 view-code = view code
 game-of-division = { $game } of the { $division }
 unassisted = unassisted
@@ -43,3 +74,197 @@ reg_losses_short = L
 ot_wins_short = OTW
 ot_losses_short = OTL
 ties_short = T
+pp_percentage_short = PP%
+pk_percentage_short = PK%
+rank_short = RK
+goals_for_short = GF
+goal_difference_short = DIFF
+tiebreak = tiebreaker
+tiebreak-head-to-head-points = head-to-head points
+tiebreak-head-to-head-goal-difference = head-to-head goal difference
+tiebreak-head-to-head-goals-scored = head-to-head goals scored
+tiebreak-goal-difference = goal difference
+tiebreak-unresolved = tied
+points-system = Points ({ $name }): { $reg_win } for a regulation win, { $ot_win } for an overtime or shootout win, { $ot_loss } for an overtime or shootout loss, { $reg_loss } for a regulation loss.
+points-system-ties = A tie is worth { $tie } { $tie ->
+    [one] point
+   *[other] points
+}.
+points-system-no-ties = Games can not end in a tie.
+goalie_plural = goalies
+games_played_short = GP
+shots_against_short = SA
+saves_short = SV
+goals_against_short = GA
+save_percentage_short = SV%
+goals_against_average_short = GAA
+shutouts_short = SO
+shot_attempts_short = ATT
+shots_on_goal_short = SOG
+missed_shots_short = MISS
+shots_blocked_short = BLKD
+blocks_short = BLK
+shooting_percentage_short = SH%
+login-invalid = Invalid username or password.
+forbidden = You do not have permission to view this page.
+forbidden-login = Please log in with an account that has access.
+not-found = This page does not exist.
+back-to-leagues = Back to the leagues
+server-error = Something went wrong on our end; please try again later.
+admin = administration
+logged-in-as = Logged in as { $user }
+user_plural = users
+role_plural = roles
+games = games
+new-game = add a game
+edit-game = edit the game
+game-name = name
+start-time = start time
+end-time = end time
+save-game = save the game
+logout = log out
+scorekeeping = scorekeeping
+record-shot = record shot
+goalie = goalie
+blocker = blocker
+on-net = on net
+penalty = penalty
+penalty_plural = penalties
+penalty-summary = penalty summary
+record-penalty = record penalty
+no-penalties = No penalties have been called.
+shootout-summary = shootout summary
+record-shootout-attempt = record shootout attempt
+attempt-number = attempt
+no-goal = no goal
+no-shootout = There was no shootout.
+season = season
+show-season = show season
+season-archive = past seasons
+season-dates = From { $start } to { $end }
+no-past-seasons = No season has ended yet.
+roster = roster
+schedule = schedule
+record = record
+totals = totals
+home = home
+away = away
+opponent = opponent
+date = date
+result = result
+position = position
+no-roster = No players have been dressed for this team.
+not-played = not played yet
+team-logo = { $team } logo
+outcome-REG_WIN = W
+outcome-REG_LOSS = L
+outcome-OT_WIN = OTW
+outcome-OT_LOSS = OTL
+outcome-SO_WIN = SOW
+outcome-SO_LOSS = SOL
+outcome-TIE = T
+league = league
+division = division
+game-log = game log
+stats-by-division = stats by division
+latest-competition = Latest competition: { $league }
+lifetime-stats = lifetime stats
+no-player-games = This player has not been dressed for any game yet.
+scoring-leaders = scoring leaders
+min-games = minimum games played
+show-leaders = show leaders
+no-leaders = No player has played enough games.
+classification = classification
+classification-B1 = B1
+classification-B2 = B2
+classification-B3 = B3
+classification-SIGHTED = sighted
+unclassified = not classified
+effective-date = effective date
+lineup-problems = lineup problems
+lineup-unclassified = { $team } #{ $number } { $name } has no classification on the day of the game.
+lineup-sighted-skater = { $team } #{ $number } { $name } is sighted, but only goalies may be sighted.
+lineup-too-few = { $team } has { $count } { $classification } players dressed, but needs at least { $limit }.
+lineup-too-many = { $team } has { $count } { $classification } players dressed, but may have at most { $limit }.
+lineup = lineup
+edit-lineups = edit lineups
+player = player
+number = number
+empty-lineup = No player is dressed yet.
+add-to-lineup = add to lineup
+remove-from-lineup = remove
+copy-previous-lineup = copy the lineup of the previous game
+infraction = infraction
+minutes = minutes
+served-by = served by
+served-by-offender = the penalized player
+pim_short = PIM
+pim = penalty minutes
+strength = strength
+strength-EV = EV
+strength-PP = PP
+strength-SH = SH
+strength-EN = EN
+shooter-skaters = skaters of the shooter's team
+opponent-skaters = skaters of the other team
+empty-net = empty net
+no-periods = No periods have been added to this game.
+error-shooter-not-in-game = The shooter is not dressed for this game.
+error-goalie-not-in-game = The goalie is not dressed for this game.
+error-goalie-same-team = The goalie must be on the other team from the shooter.
+error-blocker-not-in-game = The blocker is not dressed for this game.
+error-blocker-same-team = The blocker must be on the other team from the shooter.
+error-blocked-goal = A blocked shot cannot be a goal.
+error-goal-not-on-net = A goal must be on net.
+error-assist-without-goal = Only goals can have assists.
+error-second-assist-only = A secondary assist requires a first assist.
+error-assistant-not-in-game = An assistant is not dressed for this game.
+error-assistant-wrong-team = Assistants must be teammates of the shooter.
+error-assistant-twice = The same player cannot assist twice on one goal.
+error-period-not-in-game = The period is not part of this game.
+error-invalid-time = The time must be written as MM:SS.
+error-time-past-period = The time is longer than the period.
+error-shot-in-shootout = Shootout attempts are not recorded as shots.
+error-shootout-not-tied = Only a tied game can go to a shootout.
+error-shootout-same-team-twice = The teams must take turns shooting in the shootout.
+error-offender-not-in-game = The penalized player is not dressed for this game.
+error-served-by-not-in-game = The player serving the penalty is not dressed for this game.
+error-served-by-wrong-team = The penalty must be served by a teammate of the penalized player.
+error-unknown-infraction = Please choose an infraction.
+error-invalid-minutes = Please choose a valid penalty length.
+error-invalid-skaters = Each team has between 3 and 5 skaters on the ice.
+error-unknown-player = Please choose a player.
+error-player-already-dressed = The player is already dressed for this game.
+error-invalid-player-number = The number must be between 0 and 99.
+error-unknown-position = Please choose a position.
+error-player-number-taken = Another player on the team already wears this number.
+error-player-not-in-lineup = The player is not in this team’s lineup.
+error-player-has-events = The player has shots, penalties or shootout attempts in this game.
+error-nothing-to-copy = The team has no earlier game with a lineup to copy, or everyone is already dressed.
+error-missing-game-name = The game needs a name in every language.
+error-invalid-date-time = The start and end times must be valid dates and times.
+error-game-ends-before-start = The game must end after it starts.
+error-same-home-and-away = The home and away teams must be different.
+error-team-not-in-division = Both teams must be in the game’s division.
+error-dressed-team-removed = A team which would no longer play this game already has a lineup for it.
+goal-announcement = { $team } { $position } #{ $number } { $name } has scored! Time of the goal: { $time } in the { $period }.
+position-C = centre
+position-R = right wing
+position-L = left wing
+position-D = defence
+position-G = goalie
+position-HC = head coach
+position-AC = assistant coach
+period-1 = first period
+period-2 = second period
+period-3 = third period
+period-OT = overtime
+period-SO = shootout
+period-2OT = second overtime
+period-3OT = third overtime
+period-4OT = fourth overtime
+period-5OT = fifth overtime
+period-6OT = sixth overtime
+period-7OT = seventh overtime
+period-8OT = eighth overtime
+period-9OT = ninth overtime
@@ -1,5 +1,29 @@
 game_url = /:lang/match/:id/
 game_url_tmpl = /{ $lang }/match/{ $id }/
+live_url = /:lang/match/:id/direct/
+live_url_tmpl = /{ $lang }/match/{ $id }/direct/
+scorekeeper_url = /:lang/match/:id/marqueur/
+scorekeeper_url_tmpl = /{ $lang }/match/{ $id }/marqueur/
+scorekeeper_penalty_url = /:lang/match/:id/marqueur/penalite/
+scorekeeper_penalty_url_tmpl = /{ $lang }/match/{ $id }/marqueur/penalite/
+scorekeeper_shootout_url = /:lang/match/:id/marqueur/tirs-de-barrage/
+scorekeeper_shootout_url_tmpl = /{ $lang }/match/{ $id }/marqueur/tirs-de-barrage/
+lineup_url = /:lang/match/:id/alignement/:team/
+lineup_url_tmpl = /{ $lang }/match/{ $id }/alignement/{ $team }/
+lineup_remove_url = /:lang/match/:id/alignement/:team/retirer/
+lineup_remove_url_tmpl = /{ $lang }/match/{ $id }/alignement/{ $team }/retirer/
+lineup_copy_url = /:lang/match/:id/alignement/:team/copier/
+lineup_copy_url_tmpl = /{ $lang }/match/{ $id }/alignement/{ $team }/copier/
+season_archive_url = /:lang/ligue/:id/saisons/
+season_archive_url_tmpl = /{ $lang }/ligue/{ $id }/saisons/
+league_leaders_url = /:lang/ligue/:id/meneurs/
+league_leaders_url_tmpl = /{ $lang }/ligue/{ $id }/meneurs/
+division_leaders_url = /:lang/division/:id/meneurs/
+division_leaders_url_tmpl = /{ $lang }/division/{ $id }/meneurs/
+team_url = /:lang/equipe/:id/
+team_url_tmpl = /{ $lang }/equipe/{ $id }/
+player_url = /:lang/joueur/:id/
+player_url_tmpl = /{ $lang }/joueur/{ $id }/
 view-code = voir le code
 game-of-division = { $game } de le { $division }
 unassisted = non assisté
@@ -32,3 +56,197 @@ reg_losses_short = P
 ot_wins_short = PW
 ot_losses_short = PL
 ties_short = E
+pp_percentage_short = AN%
+pk_percentage_short = DN%
+rank_short = RG
+goals_for_short = BP
+goal_difference_short = DIFF
+tiebreak = bris d’égalité
+tiebreak-head-to-head-points = points entre les équipes à égalité
+tiebreak-head-to-head-goal-difference = différence de buts entre les équipes à égalité
+tiebreak-head-to-head-goals-scored = buts marqués entre les équipes à égalité
+tiebreak-goal-difference = différence de buts
+tiebreak-unresolved = à égalité
+points-system = Points ({ $name }) : { $reg_win } pour une victoire en temps réglementaire, { $ot_win } pour une victoire en prolongation ou en tirs de barrage, { $ot_loss } pour une défaite en prolongation ou en tirs de barrage, { $reg_loss } pour une défaite en temps réglementaire.
+points-system-ties = Un match nul vaut { $tie } { $tie ->
+    [one] point
+   *[other] points
+}.
+points-system-no-ties = Les matchs ne peuvent pas se terminer par un match nul.
+goalie_plural = gardiens
+games_played_short = PJ
+shots_against_short = TC
+saves_short = ARR
+goals_against_short = BC
+save_percentage_short = %ARR
+goals_against_average_short = MOY
+shutouts_short = BL
+shot_attempts_short = TT
+shots_on_goal_short = TB
+missed_shots_short = TR
+shots_blocked_short = TBQ
+blocks_short = BLO
+shooting_percentage_short = %T
+login-invalid = Nom d’utilisateur ou mot de passe invalide.
+forbidden = Vous n’avez pas la permission de voir cette page.
+forbidden-login = Veuillez vous connecter avec un compte qui y a accès.
+not-found = Cette page n’existe pas.
+back-to-leagues = Retour aux ligues
+server-error = Une erreur s’est produite de notre côté; veuillez réessayer plus tard.
+admin = administration
+logged-in-as = Connecté en tant que { $user }
+user_plural = utilisateurs
+role_plural = rôles
+games = matchs
+new-game = ajouter un match
+edit-game = modifier le match
+game-name = nom
+start-time = heure de début
+end-time = heure de fin
+save-game = enregistrer le match
+logout = se déconnecter
+scorekeeping = marquage
+record-shot = enregistrer le tir
+goalie = gardien
+blocker = bloqueur
+on-net = sur le filet
+penalty = pénalité
+penalty_plural = pénalités
+penalty-summary = résumé des pénalités
+record-penalty = enregistrer la pénalité
+no-penalties = Aucune pénalité n’a été décernée.
+shootout-summary = résumé des tirs de barrage
+record-shootout-attempt = enregistrer le tir de barrage
+attempt-number = tentative
+no-goal = pas de but
+no-shootout = Il n’y a pas eu de tirs de barrage.
+season = saison
+show-season = afficher la saison
+season-archive = saisons passées
+season-dates = Du { $start } au { $end }
+no-past-seasons = Aucune saison n’est encore terminée.
+roster = formation
+schedule = calendrier
+record = fiche
+totals = totaux
+home = domicile
+away = visiteur
+opponent = adversaire
+date = date
+result = résultat
+position = position
+no-roster = Aucun joueur n’a été inscrit pour cette équipe.
+not-played = pas encore joué
+team-logo = logo des { $team }
+outcome-REG_WIN = V
+outcome-REG_LOSS = D
+outcome-OT_WIN = VP
+outcome-OT_LOSS = DP
+outcome-SO_WIN = VTB
+outcome-SO_LOSS = DTB
+outcome-TIE = N
+league = ligue
+division = division
+game-log = fiche match par match
+stats-by-division = statistiques par division
+latest-competition = Dernière compétition : { $league }
+lifetime-stats = statistiques en carrière
+no-player-games = Ce joueur n’a encore été inscrit à aucun match.
+scoring-leaders = meneurs
+min-games = nombre minimal de matchs joués
+show-leaders = afficher les meneurs
+no-leaders = Aucun joueur n’a joué assez de matchs.
+classification = classification
+classification-B1 = B1
+classification-B2 = B2
+classification-B3 = B3
+classification-SIGHTED = voyant
+unclassified = non classifié
+effective-date = date d’entrée en vigueur
+lineup-problems = problèmes d’alignement
+lineup-unclassified = { $team } #{ $number } { $name } n’a pas de classification le jour du match.
+lineup-sighted-skater = { $team } #{ $number } { $name } est voyant, mais seuls les gardiens peuvent être voyants.
+lineup-too-few = { $team } a { $count } joueurs { $classification } en uniforme, mais doit en avoir au moins { $limit }.
+lineup-too-many = { $team } a { $count } joueurs { $classification } en uniforme, mais peut en avoir au plus { $limit }.
+lineup = alignement
+edit-lineups = modifier les alignements
+player = joueur
+number = numéro
+empty-lineup = Aucun joueur n’est en uniforme pour l’instant.
+add-to-lineup = ajouter à l’alignement
+remove-from-lineup = retirer
+copy-previous-lineup = copier l’alignement du match précédent
+infraction = infraction
+minutes = minutes
+served-by = purgée par
+served-by-offender = le joueur pénalisé
+pim_short = MP
+pim = minutes de pénalité
+strength = situation
+strength-EV = FÉ
+strength-PP = AN
+strength-SH = DN
+strength-EN = FD
+shooter-skaters = patineurs de l’équipe du tireur
+opponent-skaters = patineurs de l’autre équipe
+empty-net = filet désert
+no-periods = Aucune période n’a été ajoutée à ce match.
+error-shooter-not-in-game = Le tireur n’est pas inscrit à ce match.
+error-goalie-not-in-game = Le gardien n’est pas inscrit à ce match.
+error-goalie-same-team = Le gardien doit être de l’autre équipe que le tireur.
+error-blocker-not-in-game = Le bloqueur n’est pas inscrit à ce match.
+error-blocker-same-team = Le bloqueur doit être de l’autre équipe que le tireur.
+error-blocked-goal = Un tir bloqué ne peut pas être un but.
+error-goal-not-on-net = Un but doit être sur le filet.
+error-assist-without-goal = Seuls les buts peuvent avoir des aides.
+error-second-assist-only = Une aide secondaire exige une première aide.
+error-assistant-not-in-game = Un passeur n’est pas inscrit à ce match.
+error-assistant-wrong-team = Les passeurs doivent être coéquipiers du tireur.
+error-assistant-twice = Le même joueur ne peut pas obtenir deux aides sur un but.
+error-period-not-in-game = La période ne fait pas partie de ce match.
+error-invalid-time = Le temps doit être écrit sous la forme MM:SS.
+error-time-past-period = Le temps dépasse la durée de la période.
+error-shot-in-shootout = Les tirs de barrage ne sont pas enregistrés comme des tirs.
+error-shootout-not-tied = Seul un match à égalité peut aller en tirs de barrage.
+error-shootout-same-team-twice = Les équipes doivent tirer à tour de rôle en tirs de barrage.
+error-offender-not-in-game = Le joueur pénalisé n’est pas inscrit à ce match.
+error-served-by-not-in-game = Le joueur qui purge la pénalité n’est pas inscrit à ce match.
+error-served-by-wrong-team = La pénalité doit être purgée par un coéquipier du joueur pénalisé.
+error-unknown-infraction = Veuillez choisir une infraction.
+error-invalid-minutes = Veuillez choisir une durée de pénalité valide.
+error-invalid-skaters = Chaque équipe a entre 3 et 5 patineurs sur la glace.
+error-unknown-player = Veuillez choisir un joueur.
+error-player-already-dressed = Le joueur est déjà en uniforme pour ce match.
+error-invalid-player-number = Le numéro doit être entre 0 et 99.
+error-unknown-position = Veuillez choisir une position.
+error-player-number-taken = Un autre joueur de l’équipe porte déjà ce numéro.
+error-player-not-in-lineup = Le joueur n’est pas dans l’alignement de cette équipe.
+error-player-has-events = Le joueur a des tirs, des pénalités ou des tirs de barrage dans ce match.
+error-nothing-to-copy = L’équipe n’a aucun match précédent avec un alignement à copier, ou tout le monde est déjà en uniforme.
+error-missing-game-name = Le match doit avoir un nom dans chaque langue.
+error-invalid-date-time = Les heures de début et de fin doivent être des dates et heures valides.
+error-game-ends-before-start = Le match doit finir après avoir commencé.
+error-same-home-and-away = Les équipes locale et visiteuse doivent être différentes.
+error-team-not-in-division = Les deux équipes doivent faire partie de la division du match.
+error-dressed-team-removed = Une équipe qui ne jouerait plus ce match a déjà un alignement pour celui-ci.
+goal-announcement = But des { $team }! { $position } #{ $number } { $name } a marqué à { $time } de la { $period }.
+position-C = Centre
+position-R = Ailier droit
+position-L = Ailier gauche
+position-D = Défenseur
+position-G = Gardien
+position-HC = Entraîneur-chef
+position-AC = Entraîneur adjoint
+period-1 = première période
+period-2 = deuxième période
+period-3 = troisième période
+period-OT = prolongation
+period-SO = séance de tirs de barrage
+period-2OT = deuxième prolongation
+period-3OT = troisième prolongation
+period-4OT = quatrième prolongation
+period-5OT = cinquième prolongation
+period-6OT = sixième prolongation
+period-7OT = septième prolongation
+period-8OT = huitième prolongation
+period-9OT = neuvième prolongation